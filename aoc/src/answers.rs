@@ -0,0 +1,177 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use common::{Day, Part};
+use serde::{Deserialize, Serialize};
+
+/// Answer recorded for one part of a day.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Recorded {
+    pub answer: String,
+    /// Whether the answer was accepted by the puzzle website.
+    #[serde(default)]
+    pub confirmed: bool,
+}
+
+/// Content of a day's `answers.toml`.
+#[derive(Default, Debug, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Recorded>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Recorded>,
+}
+
+impl Answers {
+    pub fn path(day: &Day) -> PathBuf {
+        Path::new(day.dir).join("answers.toml")
+    }
+
+    /// Loads the answers of a day, a missing file meaning nothing was recorded yet.
+    pub fn load(day: &Day) -> anyhow::Result<Self> {
+        let path = Self::path(day);
+        match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .with_context(|| format!("Malformed answers in {}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e).with_context(|| format!("Unable to read {}", path.display())),
+        }
+    }
+
+    pub fn save(&self, day: &Day) -> anyhow::Result<()> {
+        let path = Self::path(day);
+        fs::write(&path, toml::to_string(self)?)
+            .with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    /// Whether no part has a recorded answer.
+    pub fn is_empty(&self) -> bool {
+        self.part1.is_none() && self.part2.is_none()
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Recorded> {
+        match part {
+            Part::Part1 => self.part1.as_ref(),
+            Part::Part2 => self.part2.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, recorded: Recorded) {
+        match part {
+            Part::Part1 => self.part1 = Some(recorded),
+            Part::Part2 => self.part2 = Some(recorded),
+        }
+    }
+}
+
+/// Outcome of comparing a fresh output against the recorded answer.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Status {
+    /// Same as the confirmed answer.
+    Ok,
+    /// Differs from the confirmed answer.
+    Mismatch { expected: String },
+    /// Same as an answer which was never confirmed.
+    Unconfirmed,
+    /// Differs from an answer which was never confirmed.
+    Changed { previous: String },
+    /// Nothing recorded for this part.
+    Missing,
+}
+
+impl Status {
+    pub fn check(recorded: Option<&Recorded>, output: &str) -> Self {
+        match recorded {
+            None => Status::Missing,
+            Some(Recorded { answer, confirmed }) if answer == output => {
+                if *confirmed {
+                    Status::Ok
+                } else {
+                    Status::Unconfirmed
+                }
+            }
+            Some(Recorded {
+                answer,
+                confirmed: true,
+            }) => Status::Mismatch {
+                expected: answer.clone(),
+            },
+            Some(Recorded { answer, .. }) => Status::Changed {
+                previous: answer.clone(),
+            },
+        }
+    }
+
+    /// Explains the status of `output` to a human.
+    pub fn describe(&self, output: &str) -> String {
+        match self {
+            Status::Ok => format!("ok {}", output),
+            Status::Mismatch { expected } => {
+                format!("MISMATCH expected {} got {}", expected, output)
+            }
+            Status::Unconfirmed => format!("unconfirmed {}", output),
+            Status::Changed { previous } => format!("changed {} -> {}", previous, output),
+            Status::Missing => format!("missing, got {}", output),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Recorded, Status};
+
+    #[test]
+    fn test_status() {
+        let confirmed = Recorded {
+            answer: "2817".into(),
+            confirmed: true,
+        };
+        let unconfirmed = Recorded {
+            confirmed: false,
+            ..confirmed.clone()
+        };
+
+        assert_eq!(Status::check(None, "2817"), Status::Missing);
+        assert_eq!(Status::check(Some(&confirmed), "2817"), Status::Ok);
+        assert_eq!(
+            Status::check(Some(&confirmed), "398"),
+            Status::Mismatch {
+                expected: "2817".into()
+            }
+        );
+        assert_eq!(
+            Status::check(Some(&unconfirmed), "2817"),
+            Status::Unconfirmed
+        );
+        assert_eq!(
+            Status::check(Some(&unconfirmed), "398"),
+            Status::Changed {
+                previous: "2817".into()
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers_format() -> anyhow::Result<()> {
+        let answers: Answers = toml::from_str(
+            r#"[part1]
+answer = "398"
+confirmed = true
+"#,
+        )?;
+
+        assert_eq!(
+            answers.part1.as_ref().map(|r| r.answer.as_str()),
+            Some("398")
+        );
+        assert!(answers.part2.is_none());
+        assert_eq!(
+            toml::to_string(&answers)?,
+            "[part1]\nanswer = \"398\"\nconfirmed = true\n"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use common::{Day, Part};
+use itertools::Itertools;
+
+/// Timed step of a day's solution.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl Stage {
+    pub const ALL: [Stage; 3] = [
+        Stage::Parse,
+        Stage::Solve(Part::Part1),
+        Stage::Solve(Part::Part2),
+    ];
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "{}", part),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            part => Ok(Stage::Solve(part.parse()?)),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Options {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Summary of the samples taken for one stage.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let percentile = |p: usize| samples[(samples.len() * p / 100).min(samples.len() - 1)];
+
+        Some(Stats {
+            min: *samples.first()?,
+            median: percentile(50),
+            p95: percentile(95),
+        })
+    }
+}
+
+fn measure(options: Options, mut f: impl FnMut() -> anyhow::Result<()>) -> anyhow::Result<Stats> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+
+    let samples = (0..options.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f()?;
+            Ok(start.elapsed())
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    Stats::from_samples(samples).context("At least one iteration is needed")
+}
+
+/// Times the parse step and both parts of a day on the given input.
+pub fn bench_day(day: &Day, input: &str, options: Options) -> anyhow::Result<Vec<(Stage, Stats)>> {
+    let parsed = day.parse(input)?;
+
+    Stage::ALL
+        .into_iter()
+        .map(|stage| {
+            let stats = match stage {
+                Stage::Parse => measure(options, || {
+                    black_box(day.parse(black_box(input))?);
+                    Ok(())
+                }),
+                Stage::Solve(part) => measure(options, || {
+                    black_box(parsed.solve(part)?);
+                    Ok(())
+                }),
+            }
+            .with_context(|| format!("While benchmarking day{} {}", day.number, stage))?;
+
+            Ok((stage, stats))
+        })
+        .collect()
+}
+
+/// Previously saved results, one `day stage min median p95` line per stage with durations in
+/// nanoseconds.
+#[derive(Default)]
+pub struct Baseline(HashMap<(u8, Stage), Stats>);
+
+impl Baseline {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read baseline {}", path.display()))?;
+
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (day, stage, min, median, p95) = line
+                    .split_whitespace()
+                    .collect_tuple()
+                    .with_context(|| format!("Malformed baseline line {:?}", line))?;
+                let nanos =
+                    |n: &str| -> anyhow::Result<Duration> { Ok(Duration::from_nanos(n.parse()?)) };
+
+                Ok((
+                    (day.trim_start_matches("day").parse()?, stage.parse()?),
+                    Stats {
+                        min: nanos(min)?,
+                        median: nanos(median)?,
+                        p95: nanos(p95)?,
+                    },
+                ))
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(Baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let content = self
+            .0
+            .iter()
+            .sorted_by_key(|(key, _)| *key)
+            .map(|((day, stage), stats)| {
+                format!(
+                    "day{} {} {} {} {}\n",
+                    day,
+                    stage,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.p95.as_nanos()
+                )
+            })
+            .collect::<String>();
+
+        fs::write(path, content)
+            .with_context(|| format!("Unable to write baseline {}", path.display()))
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, stats: Stats) {
+        self.0.insert((day, stage), stats);
+    }
+
+    /// Relative change of the median against the baseline, in percent.
+    pub fn change(&self, day: u8, stage: Stage, stats: &Stats) -> Option<f64> {
+        let baseline = self.0.get(&(day, stage))?.median.as_secs_f64();
+        (baseline > 0.0).then(|| (stats.median.as_secs_f64() / baseline - 1.0) * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, Stage, Stats};
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=100).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(51));
+        assert_eq!(stats.p95, Duration::from_millis(96));
+        assert_eq!(Stats::from_samples(Vec::new()), None);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() -> anyhow::Result<()> {
+        let stats = Stats {
+            min: Duration::from_micros(10),
+            median: Duration::from_micros(20),
+            p95: Duration::from_micros(30),
+        };
+        let mut baseline = Baseline::default();
+        baseline.insert(15, Stage::Parse, stats);
+        baseline.insert(15, "part2".parse()?, stats);
+
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}", std::process::id()));
+        baseline.save(&path)?;
+        let loaded = Baseline::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let slower = Stats {
+            median: Duration::from_micros(30),
+            ..stats
+        };
+        assert_eq!(loaded.0, baseline.0);
+        assert_eq!(loaded.change(15, Stage::Parse, &slower), Some(50.0));
+        assert_eq!(loaded.change(14, Stage::Parse, &slower), None);
+        Ok(())
+    }
+}
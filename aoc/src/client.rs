@@ -0,0 +1,251 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+
+pub const YEAR: u16 = 2021;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/mathieulj/aoc2021 by MathieuLJ@gmail.com";
+
+/// Root of the workspace, where the day crates and the `.aoc` state directory live.
+pub fn workspace_root() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// Where and how to reach the puzzle website.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    /// Directory holding the session file and the time of the last request.
+    pub state_dir: PathBuf,
+    /// Minimum delay between two requests, shared by every invocation of the runner.
+    pub min_interval: Duration,
+}
+
+impl Config {
+    /// Reads `AOC_BASE_URL` and `AOC_SESSION`, the session falling back to `.aoc/session`.
+    pub fn from_env() -> Self {
+        let state_dir = workspace_root().join(".aoc");
+        let session = env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| fs::read_to_string(state_dir.join("session")).ok())
+            .map(|session| session.trim().to_owned())
+            .filter(|session| !session.is_empty());
+
+        Config {
+            base_url: env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned()),
+            session,
+            state_dir,
+            min_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Blocking client for the puzzle website.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            config,
+        }
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn session(&self) -> anyhow::Result<&str> {
+        self.config.session.as_deref().context(
+            "No session token, set AOC_SESSION or write it to .aoc/session in the workspace",
+        )
+    }
+
+    /// Waits until `min_interval` has passed since the last request made from this workspace.
+    fn throttle(&self) -> anyhow::Result<()> {
+        let path = self.config.state_dir.join("last_request");
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+
+        let last = fs::read_to_string(&path)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(Duration::from_millis);
+        if let Some(wait) = last.and_then(|last| (last + self.config.min_interval).checked_sub(now))
+        {
+            thread::sleep(wait);
+        }
+
+        fs::create_dir_all(&self.config.state_dir)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        fs::write(&path, now.as_millis().to_string())
+            .with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    /// Prepares a request to `path`, waiting for the rate limit and attaching the session.
+    fn request(&self, method: &str, path: &str) -> anyhow::Result<ureq::Request> {
+        let session = self.session()?;
+        self.throttle()?;
+
+        Ok(self
+            .agent
+            .request(method, &self.url(path))
+            .set("Cookie", &format!("session={}", session)))
+    }
+
+    fn read(url: &str, response: Result<ureq::Response, ureq::Error>) -> anyhow::Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(429, _)) => {
+                anyhow::bail!("Rate limited by {}, try again later", url)
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                anyhow::bail!("{} is not available (yet)", url)
+            }
+            Err(ureq::Error::Status(code, response)) => anyhow::bail!(
+                "{} answered {}: {}",
+                url,
+                code,
+                response.into_string().unwrap_or_default().trim()
+            ),
+            Err(e) => Err(e).with_context(|| format!("Unable to reach {}", url)),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> anyhow::Result<String> {
+        Self::read(&self.url(path), self.request("GET", path)?.call())
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        Self::read(&self.url(path), self.request("POST", path)?.send_form(form))
+    }
+}
+
+/// Minimal HTTP server answering canned responses, standing in for the puzzle website.
+#[cfg(test)]
+pub mod test_server {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        path::PathBuf,
+        process,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use super::Config;
+
+    /// Request as seen by the server.
+    pub struct Request {
+        pub head: String,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.head.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.eq_ignore_ascii_case(name).then(|| value.trim())
+            })
+        }
+    }
+
+    /// Running server, whose state directory is removed once it is dropped.
+    pub struct Server {
+        handle: Option<JoinHandle<Vec<Request>>>,
+        state_dir: PathBuf,
+    }
+
+    impl Server {
+        /// Waits for every response to be served, returning the requests received.
+        pub fn join(&mut self) -> thread::Result<Vec<Request>> {
+            self.handle.take().expect("Server already joined").join()
+        }
+    }
+
+    impl Drop for Server {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.state_dir);
+        }
+    }
+
+    /// Serves one response per entry of `responses` then stops, with a fresh state directory so
+    /// files of other tests or earlier runs aren't seen.
+    pub fn serve(responses: Vec<(u16, String)>) -> (Config, Server) {
+        static SERVERS: AtomicUsize = AtomicUsize::new(0);
+        let state_dir = std::env::temp_dir().join(format!(
+            "aoc-state-{}-{}",
+            process::id(),
+            SERVERS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = fs::remove_dir_all(&state_dir);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let (ready, wait) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            ready.send(()).unwrap();
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                    let mut head = String::new();
+                    while reader.read_line(&mut head).unwrap() > 2 {}
+                    let mut request = Request {
+                        head,
+                        body: String::new(),
+                    };
+                    let length = request
+                        .header("Content-Length")
+                        .and_then(|length| length.parse().ok())
+                        .unwrap_or(0);
+                    let mut content = vec![0; length];
+                    reader.read_exact(&mut content).unwrap();
+                    request.body = String::from_utf8(content).unwrap();
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Canned\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        wait.recv().unwrap();
+
+        let config = Config {
+            base_url: format!("http://{}", address),
+            session: Some("cafebabe".into()),
+            state_dir: state_dir.clone(),
+            min_interval: Duration::ZERO,
+        };
+        let server = Server {
+            handle: Some(handle),
+            state_dir,
+        };
+        (config, server)
+    }
+}
@@ -0,0 +1,179 @@
+use std::{fs, path::Path};
+
+use common::Part;
+
+/// Examples found in a puzzle page, with the answers the puzzle text gives for the first one.
+#[derive(PartialEq, Eq, Debug)]
+pub struct Examples {
+    pub inputs: Vec<String>,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Text content of an HTML fragment.
+fn text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text += &rest[..start];
+        rest = rest[start..].split_once('>').map_or("", |(_, after)| after);
+    }
+    text += rest;
+    decode_entities(&text)
+}
+
+/// Contents of every `open ... close` span of `html`, in order.
+fn spans<'h>(html: &'h str, open: &'h str, close: &'h str) -> impl Iterator<Item = &'h str> {
+    html.split(open)
+        .skip(1)
+        .filter_map(move |after| after.split_once(close).map(|(inside, _)| inside))
+}
+
+/// Emphasized code of the part described by `article` which most likely is its example's answer.
+///
+/// That is the last one of the paragraphs mentioning the example, where the puzzle text concludes
+/// with the result for it, or the last one of the article when no paragraph does. Emphasized
+/// numbers after that conclusion, such as a detail of part two, are then ignored.
+fn answer(article: &str) -> Option<String> {
+    let emphasized = |html| spans(html, "<code><em>", "</em></code>").last();
+    spans(article, "<p>", "</p>")
+        .filter(|paragraph| text(paragraph).contains("example"))
+        .filter_map(emphasized)
+        .last()
+        .or_else(|| emphasized(article))
+        .map(text)
+}
+
+/// Finds the examples of a saved puzzle page and the answers to the first one.
+///
+/// The examples are the `<pre><code>` blocks of the page, which also hold the puzzle's
+/// illustrations so some may need removing. See [`answer`] for how answers are picked.
+pub fn extract(html: &str) -> anyhow::Result<Examples> {
+    let articles = spans(html, "<article", "</article>").collect::<Vec<_>>();
+    let inputs = articles
+        .iter()
+        .flat_map(|article| spans(article, "<pre><code>", "</code></pre>"))
+        .map(|block| text(block).trim_end().to_owned())
+        .collect::<Vec<_>>();
+    if inputs.is_empty() {
+        anyhow::bail!("No example in the page");
+    }
+
+    let answer = |part: Part| answer(articles.get(part as usize)?);
+    Ok(Examples {
+        inputs,
+        part1: answer(Part::Part1),
+        part2: answer(Part::Part2),
+    })
+}
+
+/// Name of every example, `<name>` alone when there is one and numbered from `<name>1` otherwise.
+pub fn names(examples: &Examples, name: &str) -> Vec<String> {
+    match examples.inputs.len() {
+        1 => vec![name.to_owned()],
+        count => (1..=count).map(|i| format!("{}{}", name, i)).collect(),
+    }
+}
+
+/// Writes the examples into `dir` as `.txt` files named by [`names`], with the answers next to the
+/// first one, keeping existing files unless `force` is set.
+pub fn save(examples: &Examples, dir: &Path, name: &str, force: bool) -> anyhow::Result<()> {
+    let names = names(examples, name);
+    let paths = names
+        .iter()
+        .map(|name| dir.join(format!("{}.txt", name)))
+        .collect::<Vec<_>>();
+    if let Some(existing) = paths.iter().find(|path| path.exists()).filter(|_| !force) {
+        anyhow::bail!(
+            "{} already exists, use --force to replace it",
+            existing.display()
+        );
+    }
+
+    fs::create_dir_all(dir)?;
+    for (path, input) in paths.iter().zip(&examples.inputs) {
+        fs::write(path, format!("{}\n", input))?;
+    }
+    for (part, answer) in [
+        (Part::Part1, &examples.part1),
+        (Part::Part2, &examples.part2),
+    ] {
+        if let Some(answer) = answer {
+            fs::write(
+                dir.join(format!("{}.{}", names[0], part)),
+                format!("{}\n", answer),
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extract, names, Examples};
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 10: Syntax Scoring ---</h2>
+<p>Some of the lines aren't corrupted, just incomplete; for example, <code>[({(&lt;(())[]&gt;[[{[]{&lt;()&lt;&gt;&gt;</code>.</p>
+<pre><code>[({(&lt;(())[]&gt;[[{[]{&lt;()&lt;&gt;&gt;
+[(()[&lt;&gt;])]({[&lt;{&lt;&lt;[]&gt;&gt;(
+</code></pre>
+<p>Stop at the first incorrect closing character on each corrupted line: <code><em>3</em></code> points.</p>
+<pre><code>): 3 points.
+</code></pre>
+<p>In the above example, the total syntax error score is <code><em>26397</em></code> points!</p>
+</article>
+<p>Your puzzle answer was <code>392421</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The middle score is <code><em>288957</em></code> because there are the same number of scores smaller and larger than it.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() -> anyhow::Result<()> {
+        let examples = extract(PAGE)?;
+        assert_eq!(
+            examples,
+            Examples {
+                inputs: vec![
+                    "[({(<(())[]>[[{[]{<()<>>\n[(()[<>])]({[<{<<[]>>(".into(),
+                    "): 3 points.".into(),
+                ],
+                part1: Some("26397".into()),
+                part2: Some("288957".into()),
+            }
+        );
+        assert_eq!(names(&examples, "example"), ["example1", "example2"]);
+
+        let part1_only = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        assert_eq!(extract(part1_only)?.part2, None);
+        assert!(extract("<article><p>Nothing</p></article>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_answer_before_other_emphasis() -> anyhow::Result<()> {
+        let page = r#"<article><h2>--- Day 6: Lanternfish ---</h2>
+<pre><code>3,4,3,1,2
+</code></pre>
+<p>In this example, after 80 days, there would be a total of <code><em>5934</em></code> fish.</p>
+<p>Each fish resets its timer to <code><em>6</em></code>, not <code><em>7</em></code>.</p>
+<p><em>How many lanternfish would there be after 80 days?</em></p>
+</article>"#;
+
+        let examples = extract(page)?;
+        assert_eq!(examples.part1.as_deref(), Some("5934"));
+        assert_eq!(names(&examples, "example"), ["example"]);
+        Ok(())
+    }
+}
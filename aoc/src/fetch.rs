@@ -0,0 +1,64 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+
+use crate::client::{Client, YEAR};
+
+/// Downloads the input of a day to `dest`, unless it is already there.
+///
+/// Returns whether the website was contacted, an existing non empty file being kept unless
+/// `force` is set.
+pub fn fetch(client: &Client, day: u8, dest: &Path, force: bool) -> anyhow::Result<bool> {
+    let cached = fs::metadata(dest).is_ok_and(|metadata| metadata.len() > 0);
+    if cached && !force {
+        return Ok(false);
+    }
+
+    let input = client.get(&format!("/{}/day/{}/input", YEAR, day))?;
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(dest, input).with_context(|| format!("Unable to write {}", dest.display()))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::fetch;
+    use crate::client::{test_server, Client};
+
+    #[test]
+    fn test_fetch_caches_input() -> anyhow::Result<()> {
+        let (config, mut server) =
+            test_server::serve(vec![(200, "199\n200\n".into()), (200, "208\n".into())]);
+        let dest = config.state_dir.join("day1/input.txt");
+        let client = Client::new(config);
+
+        assert!(fetch(&client, 1, &dest, false)?);
+        assert!(!fetch(&client, 1, &dest, false)?);
+        assert_eq!(fs::read_to_string(&dest)?, "199\n200\n");
+        assert!(fetch(&client, 1, &dest, true)?);
+        assert_eq!(fs::read_to_string(&dest)?, "208\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].head.starts_with("GET /2021/day/1/input "));
+        assert_eq!(requests[0].header("Cookie"), Some("session=cafebabe"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_unavailable() {
+        let (config, mut server) = test_server::serve(vec![(404, "Not found".into())]);
+        let dest = config.state_dir.join("day25/input.txt");
+        let client = Client::new(config);
+
+        let error = fetch(&client, 25, &dest, false).unwrap_err();
+        assert!(error.to_string().contains("not available"), "{}", error);
+        assert!(!dest.exists());
+        server.join().unwrap();
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use common::Day;
+
+/// Places a day's input is looked for when none is given, in order: `dayN.txt` in the directory
+/// named by `AOC_INPUTS` when it is set, then the `input.txt` of the day's crate.
+pub fn candidates(day: &Day) -> Vec<PathBuf> {
+    let inputs_dir = env::var_os("AOC_INPUTS").map(PathBuf::from);
+    inputs_dir
+        .map(|dir| dir.join(format!("day{}.txt", day.number)))
+        .into_iter()
+        .chain([PathBuf::from(day.dir).join("input.txt")])
+        .collect()
+}
+
+/// Whether one of the [`candidates`] holds an input.
+pub fn exists(day: &Day) -> bool {
+    candidates(day)
+        .iter()
+        .any(|path| fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0))
+}
+
+/// Reads an input from `source`, which is `-` for stdin or a path, or from the first non empty
+/// file of `candidates` when there is no source.
+fn resolve(
+    source: Option<&str>,
+    candidates: &[PathBuf],
+    mut stdin: impl Read,
+) -> anyhow::Result<String> {
+    match source {
+        Some("-") => {
+            let mut input = String::new();
+            stdin
+                .read_to_string(&mut input)
+                .context("Unable to read the input from stdin")?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path).with_context(|| format!("Unable to read {}", path)),
+        None => {
+            for path in candidates {
+                match fs::read_to_string(path) {
+                    // Empty files are placeholders waiting for the input to be downloaded
+                    Ok(input) if input.is_empty() => continue,
+                    Ok(input) => return Ok(input),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                    Err(e) => {
+                        return Err(e).with_context(|| format!("Unable to read {}", path.display()))
+                    }
+                }
+            }
+
+            let tried = candidates
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            anyhow::bail!("No input found, looked for {}", tried.join(", "))
+        }
+    }
+}
+
+/// Reads the input of `day`, from `source` if given, see [`candidates`] otherwise.
+pub fn read(day: &Day, source: Option<&str>) -> anyhow::Result<String> {
+    resolve(source, &candidates(day), io::stdin()).with_context(|| {
+        format!(
+            "Missing input for day{}, try `aoc fetch {}`",
+            day.number, day.number
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::resolve;
+
+    #[test]
+    fn test_resolve() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let (empty, input, missing) = (dir.join("empty"), dir.join("input"), dir.join("missing"));
+        fs::write(&empty, "")?;
+        fs::write(&input, "199\n200\n")?;
+        let stdin = "from stdin".as_bytes();
+
+        assert_eq!(resolve(Some("-"), &[], stdin)?, "from stdin");
+        assert_eq!(
+            resolve(input.to_str(), std::slice::from_ref(&missing), stdin)?,
+            "199\n200\n"
+        );
+        assert_eq!(
+            resolve(None, &[missing.clone(), empty.clone(), input], stdin)?,
+            "199\n200\n"
+        );
+
+        let error = resolve(None, &[missing.clone(), empty], stdin).unwrap_err();
+        assert!(error.to_string().contains(&*missing.to_string_lossy()));
+        assert!(resolve(missing.to_str(), &[], stdin).is_err());
+
+        fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,426 @@
+use anyhow::Result;
+use bench::Baseline;
+use clap::Parser;
+use common::{trace, Answer, Part};
+use registry::Selection;
+use report::Format;
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant},
+};
+
+mod answers;
+mod bench;
+mod client;
+mod extract;
+mod fetch;
+mod input;
+mod pool;
+mod registry;
+mod report;
+mod scaffold;
+mod submit;
+mod verify;
+mod watch;
+
+#[derive(Parser)]
+struct App {
+    /// Show the diagnostics of the solutions on stderr, repeat for more details
+    #[clap(short, long, global = true, parse(from_occurrences))]
+    verbose: u8,
+    /// Only show the diagnostics of this target, such as `day4`, can be repeated
+    #[clap(long, global = true, multiple_occurrences = true)]
+    target: Vec<String>,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Parser)]
+enum Command {
+    /// Run the solution of one or all days
+    Run(RunOpts),
+    /// Time the parse step and both parts of one or all days
+    Bench(BenchOpts),
+    /// Check the answers of one or all days against their recorded `answers.toml`
+    Verify(VerifyOpts),
+    /// Download the input of a day into its crate, keeping an already present one
+    Fetch(FetchOpts),
+    /// Submit the answer of a part, recording it in `answers.toml` once accepted
+    Submit(SubmitOpts),
+    /// Create the crate of a new day from the template and register it
+    New(NewOpts),
+    /// Extract the examples and answers from a saved puzzle page into a day's examples
+    Extract(ExtractOpts),
+    /// Rerun the examples and input of a day whenever they or its sources change
+    Watch(WatchOpts),
+}
+
+#[derive(Parser)]
+struct RunOpts {
+    /// Day to run, or `all` for the whole year
+    day: Selection,
+    /// Part to run, both when omitted
+    part: Option<Part>,
+    /// Path to the input file, `-` for stdin, found in the usual places when omitted
+    input: Option<String>,
+    /// Output as `text`, or `json` with one object per line and part
+    #[clap(long, default_value = "text")]
+    format: Format,
+    /// Days solved at the same time, one per CPU by default
+    #[clap(long)]
+    jobs: Option<usize>,
+    /// Seconds a part gets before it is reported as timed out, the first one including the parse
+    #[clap(long, default_value = "30")]
+    timeout: f64,
+}
+
+#[derive(Parser)]
+struct BenchOpts {
+    /// Day to benchmark, or `all` for the whole year
+    day: Selection,
+    /// Untimed runs before sampling
+    #[clap(long, default_value = "3")]
+    warmup: usize,
+    /// Timed runs of each stage
+    #[clap(long, default_value = "20")]
+    iterations: usize,
+    /// Path where the results are saved as a new baseline
+    #[clap(long)]
+    save: Option<String>,
+    /// Path of a saved baseline to compare against
+    #[clap(long)]
+    baseline: Option<String>,
+    /// Slowdown of the median, in percent, reported as a regression
+    #[clap(long, default_value = "10")]
+    threshold: f64,
+}
+
+#[derive(Parser)]
+struct VerifyOpts {
+    /// Day to verify, or `all` for the whole year
+    #[clap(default_value = "all")]
+    day: Selection,
+    /// Record the output of parts without a confirmed answer
+    #[clap(long)]
+    record: bool,
+}
+
+#[derive(Parser)]
+struct FetchOpts {
+    /// Day to download
+    day: u8,
+    /// Download again even when the input is already present
+    #[clap(long)]
+    force: bool,
+}
+
+#[derive(Parser)]
+struct SubmitOpts {
+    /// Day of the answer
+    day: u8,
+    /// Part of the answer
+    part: Part,
+    /// Answer to submit, computed from the day's input when omitted
+    answer: Option<String>,
+}
+
+#[derive(Parser)]
+struct NewOpts {
+    /// Day to create
+    day: u8,
+    /// Download the input of the day as well
+    #[clap(long)]
+    fetch: bool,
+}
+
+#[derive(Parser)]
+struct ExtractOpts {
+    /// Day the puzzle page belongs to
+    day: u8,
+    /// Path to the saved HTML page of the puzzle
+    page: String,
+    /// Name of the example files, numbered when the page has several examples
+    #[clap(long, default_value = "example")]
+    name: String,
+    /// Replace existing examples with the same names
+    #[clap(long)]
+    force: bool,
+}
+
+#[derive(Parser)]
+struct WatchOpts {
+    /// Day to watch
+    day: u8,
+    /// Seconds between two checks for changes
+    #[clap(long, default_value = "1")]
+    interval: f64,
+    /// Seconds a part gets before it is reported as timed out, the first one including the parse
+    #[clap(long, default_value = "10")]
+    timeout: f64,
+}
+
+fn main() -> Result<()> {
+    let opts = App::parse();
+    trace::init(trace::Filter {
+        level: match opts.verbose {
+            0 => trace::LevelFilter::Warn,
+            1 => trace::LevelFilter::Debug,
+            _ => trace::LevelFilter::Trace,
+        },
+        targets: opts.target,
+    })?;
+
+    match opts.command {
+        Command::Run(RunOpts {
+            day: Selection::Day(number),
+            part: Some(part),
+            input,
+            format: Format::Text,
+            ..
+        }) => {
+            // Single answer, printed bare so it can be piped around
+            let day = registry::find(number)?;
+            let data = input::read(day, input.as_deref())?;
+            println!("{}", day.run(part, &data)?);
+        }
+        Command::Run(RunOpts {
+            day,
+            part,
+            input,
+            format,
+            jobs,
+            timeout,
+        }) => {
+            if matches!(day, Selection::All) && input.is_some() {
+                anyhow::bail!("An input file can only be given when running a single day")
+            }
+            let options = pool::Options {
+                jobs: jobs.unwrap_or_else(|| {
+                    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+                }),
+                timeout: Duration::try_from_secs_f64(timeout)
+                    .map_err(|e| anyhow::anyhow!("Invalid timeout {}: {}", timeout, e))?,
+            };
+            let parts = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+            let mut queue = Vec::new();
+            let mut input_hashes = HashMap::new();
+            for &day in day.days()? {
+                let data = input::read(&day, input.as_deref());
+                if let Ok(data) = &data {
+                    input_hashes.insert(day.number, report::sha256(data));
+                }
+                queue.push(pool::Job {
+                    day,
+                    parts: parts.clone(),
+                    input: data.map(Arc::<str>::from).map_err(|e| (&e).into()),
+                });
+            }
+
+            let start = Instant::now();
+            let outcomes = pool::run(queue, options);
+
+            let mut failures = 0;
+            for outcome in &outcomes {
+                if !matches!(outcome.status, pool::Status::Solved(_)) {
+                    failures += 1;
+                }
+                if format == Format::Json {
+                    let input_hash = input_hashes.get(&outcome.day).cloned();
+                    let report = report::Report::new(outcome, input_hash);
+                    println!("{}", serde_json::to_string(&report)?);
+                    continue;
+                }
+
+                let detail = match &outcome.status {
+                    // Pictures start on their own line so they stay aligned
+                    pool::Status::Solved(answer @ Answer::Grid(_)) => format!("\n{}", answer),
+                    pool::Status::Solved(answer) => answer.to_string(),
+                    pool::Status::Failed(error) => error.message.clone(),
+                    pool::Status::Panicked(message) => message.clone(),
+                    pool::Status::TimedOut => format!("after {:.3?}", outcome.elapsed),
+                };
+                let time = |time: Option<Duration>| {
+                    time.map_or("-".to_owned(), |time| format!("{:.3?}", time))
+                };
+                println!(
+                    "day{:<2} {}  {:<7} parse {:>10}  solve {:>10}  {}",
+                    outcome.day,
+                    outcome.part,
+                    outcome.status,
+                    time(outcome.parse),
+                    time(outcome.solve),
+                    detail
+                );
+            }
+            if format == Format::Text {
+                println!(
+                    "{} ok, {} failed in {:.3?}",
+                    outcomes.len() - failures,
+                    failures,
+                    start.elapsed()
+                );
+            }
+            // Failures are part of the output, only reflected in the exit status
+            if failures > 0 {
+                anyhow::bail!("{} part(s) failed", failures);
+            }
+        }
+        Command::Bench(opts) => {
+            let options = bench::Options {
+                warmup: opts.warmup,
+                iterations: opts.iterations,
+            };
+            let baseline = opts
+                .baseline
+                .as_deref()
+                .map(|path| Baseline::load(Path::new(path)))
+                .transpose()?;
+
+            let mut results = Baseline::default();
+            let mut regressions = 0;
+            for day in opts.day.days()? {
+                let input = match input::read(day, None) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("day{} skipped: {:#}", day.number, e);
+                        continue;
+                    }
+                };
+                for (stage, stats) in bench::bench_day(day, &input, options)? {
+                    let change = baseline
+                        .as_ref()
+                        .and_then(|baseline| baseline.change(day.number, stage, &stats));
+                    let regressed = change.is_some_and(|change| change > opts.threshold);
+                    if regressed {
+                        regressions += 1;
+                    }
+
+                    println!(
+                        "day{:<2} {:<5}  min {:>10.3?}  median {:>10.3?}  p95 {:>10.3?}{}{}",
+                        day.number,
+                        stage,
+                        stats.min,
+                        stats.median,
+                        stats.p95,
+                        change.map_or(String::new(), |change| format!("  {:+.1}%", change)),
+                        if regressed { "  REGRESSION" } else { "" },
+                    );
+                    results.insert(day.number, stage, stats);
+                }
+            }
+
+            if let Some(path) = &opts.save {
+                results.save(Path::new(path))?;
+            }
+            if regressions > 0 {
+                anyhow::bail!(
+                    "{} stage(s) are more than {}% slower than the baseline",
+                    regressions,
+                    opts.threshold
+                );
+            }
+        }
+        Command::Verify(opts) => verify::verify(opts.day.days()?, opts.record)?,
+        Command::Fetch(opts) => {
+            let client = client::Client::new(client::Config::from_env());
+            let dest = client::workspace_root()
+                .join(format!("day{}", opts.day))
+                .join("input.txt");
+            if fetch::fetch(&client, opts.day, &dest, opts.force)? {
+                println!("Downloaded {}", dest.display());
+            } else {
+                println!(
+                    "{} already present, use --force to download it again",
+                    dest.display()
+                );
+            }
+        }
+        Command::Submit(opts) => {
+            let day = registry::find(opts.day)?;
+            let answer = match opts.answer {
+                Some(answer) => answer,
+                None => match day.run(opts.part, &input::read(day, None)?)? {
+                    Answer::Grid(_) => anyhow::bail!(
+                        "day{} {} is a picture, read it and pass the answer explicitly",
+                        opts.day,
+                        opts.part
+                    ),
+                    answer => answer.to_string(),
+                },
+            };
+
+            let config = client::Config::from_env();
+            let log_path = config.state_dir.join("guesses.txt");
+            let client = client::Client::new(config);
+            match submit::submit(&client, &log_path, opts.day, opts.part, &answer)? {
+                submit::Outcome::Correct => {
+                    let mut answers = answers::Answers::load(day)?;
+                    answers.set(
+                        opts.part,
+                        answers::Recorded {
+                            answer,
+                            confirmed: true,
+                        },
+                    );
+                    answers.save(day)?;
+                    println!(
+                        "Correct, recorded in {}",
+                        answers::Answers::path(day).display()
+                    );
+                }
+                submit::Outcome::Wait(wait) => {
+                    anyhow::bail!("Submitted too recently, {} left to wait", wait)
+                }
+                submit::Outcome::AlreadySolved => {
+                    anyhow::bail!("day{} {} is already solved", opts.day, opts.part)
+                }
+                outcome => anyhow::bail!("{} was rejected as {}", answer, outcome),
+            }
+        }
+        Command::New(opts) => {
+            let root = client::workspace_root();
+            scaffold::new_day(root, opts.day)?;
+            println!("Created day{}", opts.day);
+
+            if opts.fetch {
+                let client = client::Client::new(client::Config::from_env());
+                let dest = root.join(format!("day{}", opts.day)).join("input.txt");
+                fetch::fetch(&client, opts.day, &dest, false)?;
+                println!("Downloaded {}", dest.display());
+            }
+        }
+        Command::Extract(opts) => {
+            let examples = extract::extract(&fs::read_to_string(&opts.page)?)?;
+            let dir = client::workspace_root()
+                .join(format!("day{}", opts.day))
+                .join("examples");
+            extract::save(&examples, &dir, &opts.name, opts.force)?;
+
+            for (name, input) in extract::names(&examples, &opts.name)
+                .iter()
+                .zip(&examples.inputs)
+            {
+                println!("{}:\n{}", name, input);
+            }
+            for (part, answer) in [
+                (Part::Part1, &examples.part1),
+                (Part::Part2, &examples.part2),
+            ] {
+                println!("{}: {}", part, answer.as_deref().unwrap_or("-"));
+            }
+        }
+        Command::Watch(opts) => {
+            let interval = Duration::try_from_secs_f64(opts.interval)
+                .map_err(|e| anyhow::anyhow!("Invalid interval {}: {}", opts.interval, e))?;
+            let timeout = Duration::try_from_secs_f64(opts.timeout)
+                .map_err(|e| anyhow::anyhow!("Invalid timeout {}: {}", opts.timeout, e))?;
+            watch::watch(registry::find(opts.day)?, interval, timeout)?
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,364 @@
+use std::{
+    any::Any,
+    collections::VecDeque,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{Answer, Day, Part};
+
+use crate::report::Error;
+
+/// Parts of a day to solve, sharing one parse of the input.
+#[derive(Clone)]
+pub struct Job {
+    pub day: Day,
+    pub parts: Vec<Part>,
+    /// The input, or why it couldn't be read which fails the job right away.
+    pub input: Result<Arc<str>, Error>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Options {
+    /// Days solved at the same time.
+    pub jobs: usize,
+    /// Wall-clock time each part gets before it is given up on, the first one including the parse
+    /// step.
+    pub timeout: Duration,
+}
+
+#[derive(Clone, Debug)]
+pub enum Status {
+    Solved(Answer),
+    Failed(Error),
+    Panicked(String),
+    TimedOut,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Padded so statuses line up in the summary table
+        f.pad(match self {
+            Status::Solved(_) => "ok",
+            Status::Failed(_) => "error",
+            Status::Panicked(_) => "panic",
+            Status::TimedOut => "timeout",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    /// Time spent parsing the input shared by the day's parts, missing when there was no input or
+    /// the parse step never finished.
+    pub parse: Option<Duration>,
+    /// Time spent solving the part, missing when it never ran.
+    pub solve: Option<Duration>,
+    /// Time until the part finished, or until it was given up on, parse step included.
+    pub elapsed: Duration,
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or("Unknown panic".to_owned(), |message| (*message).to_owned()),
+    }
+}
+
+fn failed(message: String) -> Status {
+    Status::Failed(Error {
+        message,
+        diagnostic: None,
+    })
+}
+
+/// What the solver thread of a job reports, in order: the parse step then every part.
+enum Event {
+    /// The parse step took this long, and failed with the given status if any.
+    Parsed(Duration, Option<Status>),
+    Solved(Status, Duration),
+}
+
+/// Runs a job on its own thread, so a solver which panics or never returns only loses its own
+/// results. A timed out solver can't be stopped and keeps running until the program exits, the
+/// parts after it being failed without running.
+fn run_job(Job { day, parts, input }: Job, timeout: Duration) -> Vec<Outcome> {
+    let start = Instant::now();
+    let outcome = |part, status, parse, solve| Outcome {
+        day: day.number,
+        part,
+        status,
+        parse,
+        solve,
+        elapsed: start.elapsed(),
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            return parts
+                .iter()
+                .map(|&part| outcome(part, Status::Failed(error.clone()), None, None))
+                .collect()
+        }
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let solver_parts = parts.clone();
+    let spawned = thread::Builder::new()
+        .name(format!("day{}", day.number))
+        .spawn(move || {
+            let start = Instant::now();
+            let parsed = panic::catch_unwind(AssertUnwindSafe(|| day.parse(&input)));
+            let parse = start.elapsed();
+            let parsed = match parsed {
+                Ok(Ok(parsed)) => parsed,
+                Ok(Err(e)) => {
+                    let _ = sender.send(Event::Parsed(parse, Some(Status::Failed((&e).into()))));
+                    return;
+                }
+                Err(payload) => {
+                    let status = Status::Panicked(panic_message(payload));
+                    let _ = sender.send(Event::Parsed(parse, Some(status)));
+                    return;
+                }
+            };
+            if sender.send(Event::Parsed(parse, None)).is_err() {
+                return;
+            }
+
+            for part in solver_parts {
+                let start = Instant::now();
+                let status = match panic::catch_unwind(AssertUnwindSafe(|| parsed.solve(part))) {
+                    Ok(Ok(answer)) => Status::Solved(answer),
+                    Ok(Err(e)) => Status::Failed((&e).into()),
+                    Err(payload) => Status::Panicked(panic_message(payload)),
+                };
+                if sender.send(Event::Solved(status, start.elapsed())).is_err() {
+                    return;
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        let message = format!("Unable to start the solver: {}", e);
+        return parts
+            .iter()
+            .map(|&part| outcome(part, failed(message.clone()), None, None))
+            .collect();
+    }
+
+    // Each part has until its own deadline, the first one sharing it with the parse step
+    let mut deadline = start + timeout;
+    let mut next = || {
+        let event = receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()));
+        deadline = Instant::now() + timeout;
+        event
+    };
+    let parse = match next() {
+        Ok(Event::Parsed(parse, None)) => Some(parse),
+        Ok(Event::Parsed(parse, Some(status))) => {
+            return parts
+                .iter()
+                .map(|&part| outcome(part, status.clone(), Some(parse), None))
+                .collect()
+        }
+        Ok(Event::Solved(..)) => unreachable!("Parts are only solved once parsed"),
+        Err(e) => {
+            let status = match e {
+                mpsc::RecvTimeoutError::Timeout => Status::TimedOut,
+                mpsc::RecvTimeoutError::Disconnected => {
+                    Status::Panicked("The solver stopped without parsing".to_owned())
+                }
+            };
+            return parts
+                .iter()
+                .enumerate()
+                .map(|(i, &part)| match i {
+                    0 => outcome(part, status.clone(), None, None),
+                    _ => {
+                        let message = "Not run, the parse step didn't finish".to_owned();
+                        outcome(part, failed(message), None, None)
+                    }
+                })
+                .collect();
+        }
+    };
+
+    let mut outcomes = Vec::new();
+    let mut stuck = None;
+    for &part in &parts {
+        if let Some(stuck) = stuck {
+            let message = format!("Not run, {} didn't finish", stuck);
+            outcomes.push(outcome(part, failed(message), parse, None));
+            continue;
+        }
+        outcomes.push(match next() {
+            Ok(Event::Solved(status, solve)) => outcome(part, status, parse, Some(solve)),
+            Ok(Event::Parsed(..)) => unreachable!("The input is only parsed once"),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                stuck = Some(part);
+                outcome(part, Status::TimedOut, parse, None)
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                stuck = Some(part);
+                let message = "The solver stopped without an answer".to_owned();
+                outcome(part, Status::Panicked(message), parse, None)
+            }
+        });
+    }
+    outcomes
+}
+
+/// Runs every job, at most `options.jobs` at once, and returns the outcomes of their parts in the
+/// order of `jobs`.
+pub fn run(jobs: Vec<Job>, options: Options) -> Vec<Outcome> {
+    let count = jobs.len();
+    let queue = Arc::new(Mutex::new(
+        jobs.into_iter().enumerate().collect::<VecDeque<_>>(),
+    ));
+    let (sender, receiver) = mpsc::channel();
+
+    for _ in 0..options.jobs.clamp(1, count.max(1)) {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+        thread::spawn(move || loop {
+            let next = queue.lock().unwrap().pop_front();
+            match next {
+                Some((index, job)) => {
+                    if sender.send((index, run_job(job, options.timeout))).is_err() {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        });
+    }
+    drop(sender);
+
+    let mut outcomes = receiver.iter().collect::<Vec<_>>();
+    outcomes.sort_by_key(|(index, _)| *index);
+    outcomes
+        .into_iter()
+        .flat_map(|(_, outcomes)| outcomes)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread, time::Duration};
+
+    use common::Part;
+
+    use super::{run, Job, Options, Status};
+    use crate::report::Error;
+
+    /// Solves `part1` by its input, and fails `part2` in the way the input says.
+    struct Misbehaving;
+
+    impl common::Solution for Misbehaving {
+        type Parsed<'i> = &'i str;
+        type Answer1 = String;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> anyhow::Result<&str> {
+            match input {
+                "unparsable" => anyhow::bail!("Unexpected input"),
+                "slow" => {
+                    thread::sleep(Duration::from_secs(60));
+                    Ok(input)
+                }
+                _ => Ok(input),
+            }
+        }
+
+        fn part1(input: &&str) -> anyhow::Result<String> {
+            Ok(input.to_string())
+        }
+
+        fn part2(input: &&str) -> anyhow::Result<u32> {
+            match *input {
+                "panic" => panic!("Lost in the caves"),
+                "hang" => {
+                    thread::sleep(Duration::from_secs(60));
+                    Ok(0)
+                }
+                _ => anyhow::bail!("No path"),
+            }
+        }
+    }
+
+    common::register_day!(12, Misbehaving);
+
+    #[test]
+    fn test_run() {
+        let mut jobs = ["panic", "hang", "error", "unparsable", "slow"]
+            .into_iter()
+            .map(|input| Job {
+                day: DAY,
+                parts: Part::ALL.to_vec(),
+                input: Ok(Arc::from(input)),
+            })
+            .collect::<Vec<_>>();
+        jobs.push(Job {
+            day: DAY,
+            parts: vec![Part::Part2, Part::Part1],
+            input: Ok(Arc::from("hang")),
+        });
+        jobs.push(Job {
+            day: DAY,
+            parts: vec![Part::Part1],
+            input: Err(Error {
+                message: "No input found".to_owned(),
+                diagnostic: None,
+            }),
+        });
+        let outcomes = run(
+            jobs,
+            Options {
+                jobs: 2,
+                timeout: Duration::from_millis(200),
+            },
+        );
+
+        let statuses = outcomes
+            .iter()
+            .map(|outcome| match &outcome.status {
+                Status::Solved(answer) => format!("{} {}", outcome.part, answer),
+                Status::Failed(e) => format!("{} {}", outcome.part, e.message),
+                Status::Panicked(e) => format!("{} {}", outcome.part, e),
+                Status::TimedOut => format!("{} timeout", outcome.part),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses,
+            [
+                "part1 panic",
+                "part2 Lost in the caves",
+                "part1 hang",
+                "part2 timeout",
+                "part1 error",
+                "part2 No path",
+                "part1 Unexpected input",
+                "part2 Unexpected input",
+                "part1 timeout",
+                "part2 Not run, the parse step didn't finish",
+                "part2 timeout",
+                "part1 Not run, part2 didn't finish",
+                "part1 No input found",
+            ]
+        );
+        assert!(matches!(outcomes[1].status, Status::Panicked(_)));
+        assert!(outcomes[3].elapsed < Duration::from_secs(60));
+        assert!(outcomes[0].parse.is_some() && outcomes[0].solve.is_some());
+        assert!(outcomes[3].solve.is_none());
+        assert!(outcomes[7].parse.is_some() && outcomes[7].solve.is_none());
+        assert!(outcomes[8].parse.is_none());
+    }
+}
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use common::Day;
+
+/// Every day known to the runner, in calendar order.
+pub const DAYS: &[Day] = &[
+    day1::DAY,
+    day2::DAY,
+    day3::DAY,
+    day4::DAY,
+    day5::DAY,
+    day6::DAY,
+    day7::DAY,
+    day8::DAY,
+    day9::DAY,
+    day10::DAY,
+    day11::DAY,
+    day12::DAY,
+    day13::DAY,
+    day14::DAY,
+    day15::DAY,
+    day16::DAY,
+    day17::DAY,
+    day18::DAY,
+    day19::DAY,
+    day20::DAY,
+    day21::DAY,
+    day22::DAY,
+    day23::DAY,
+    // INSERT HERE
+];
+
+pub fn find(number: u8) -> anyhow::Result<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .with_context(|| format!("Day {} is not registered", number))
+}
+
+/// Days picked on the command line, either one or the whole year.
+#[derive(Clone, Copy)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+impl Selection {
+    pub fn days(self) -> anyhow::Result<&'static [Day]> {
+        match self {
+            Selection::All => Ok(DAYS),
+            Selection::Day(number) => Ok(std::slice::from_ref(find(number)?)),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            Ok(Selection::All)
+        } else {
+            Ok(Selection::Day(s.trim_start_matches("day").parse()?))
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, str::FromStr};
+
+use common::ParseError;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::pool::{Outcome, Status};
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Text,
+    /// One JSON object per line and part, see [`Report`].
+    Json,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => anyhow::bail!("Unknown format {:?}, expected text or json", s),
+        }
+    }
+}
+
+/// Where in the input parsing failed, see [`ParseError`].
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub expected: Vec<String>,
+    pub errors: Vec<String>,
+    pub contexts: Vec<&'static str>,
+}
+
+/// Failure of a part, or of the parse step both parts share.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize)]
+pub struct Error {
+    pub message: String,
+    /// Only present for errors of the parse step.
+    pub diagnostic: Option<Diagnostic>,
+}
+
+impl From<&anyhow::Error> for Error {
+    fn from(error: &anyhow::Error) -> Self {
+        Error {
+            message: format!("{:#}", error),
+            diagnostic: error.downcast_ref::<ParseError>().map(|error| Diagnostic {
+                line: error.line,
+                column: error.column,
+                source_line: error.source_line.clone(),
+                expected: error.expected.clone(),
+                errors: error.errors.clone(),
+                contexts: error.contexts.clone(),
+            }),
+        }
+    }
+}
+
+/// Outcome of solving one part of a day.
+#[derive(PartialEq, Eq, Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: String,
+    /// `ok`, `error`, `panic` or `timeout`, see [`Status`].
+    pub status: String,
+    pub answer: Option<String>,
+    /// Time spent parsing the input, shared by both parts, missing without an input.
+    pub parse_ns: Option<u64>,
+    /// Time spent solving the part, missing when it never ran or didn't finish.
+    pub solve_ns: Option<u64>,
+    /// SHA-256 of the input, in hexadecimal, missing without an input.
+    pub input_hash: Option<String>,
+    pub error: Option<Error>,
+}
+
+/// SHA-256 of an input, in hexadecimal.
+pub fn sha256(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+impl Report {
+    /// Report of a part solved by the pool, every failure turned into an error.
+    pub fn new(outcome: &Outcome, input_hash: Option<String>) -> Self {
+        let message = |message| Error {
+            message,
+            diagnostic: None,
+        };
+        let (answer, error) = match &outcome.status {
+            Status::Solved(answer) => (Some(answer.to_string()), None),
+            Status::Failed(error) => (None, Some(error.clone())),
+            Status::Panicked(panic) => (None, Some(message(format!("Panicked: {}", panic)))),
+            Status::TimedOut => {
+                let timeout = format!("Timed out after {:.3?}", outcome.elapsed);
+                (None, Some(message(timeout)))
+            }
+        };
+        Report {
+            day: outcome.day,
+            part: outcome.part.to_string(),
+            status: outcome.status.to_string(),
+            answer,
+            parse_ns: outcome.parse.map(|parse| parse.as_nanos() as u64),
+            solve_ns: outcome.solve.map(|solve| solve.as_nanos() as u64),
+            input_hash,
+            error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use common::Part;
+
+    use super::{sha256, Error, Report};
+    use crate::pool::{self, Job, Options};
+
+    struct Numbers;
+
+    impl common::Solution for Numbers {
+        type Parsed<'i> = Vec<u32>;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+            Ok(common::parse(
+                input,
+                nom::multi::separated_list1(
+                    nom::character::complete::char(','),
+                    nom::character::complete::u32,
+                ),
+            )?)
+        }
+
+        fn part1(numbers: &Vec<u32>) -> anyhow::Result<u32> {
+            Ok(numbers.iter().sum())
+        }
+
+        fn part2(_: &Vec<u32>) -> anyhow::Result<u32> {
+            anyhow::bail!("Not solved yet")
+        }
+    }
+
+    common::register_day!(1, Numbers);
+
+    fn run(input: Result<&str, &anyhow::Error>) -> Vec<Report> {
+        let job = Job {
+            day: DAY,
+            parts: Part::ALL.to_vec(),
+            input: input.map(Arc::from).map_err(Error::from),
+        };
+        let options = Options {
+            jobs: 1,
+            timeout: Duration::from_secs(10),
+        };
+        let input_hash = input.ok().map(sha256);
+        pool::run(vec![job], options)
+            .iter()
+            .map(|outcome| Report::new(outcome, input_hash.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_report() {
+        let reports = run(Ok("1,2,3"));
+        assert_eq!(reports[0].answer.as_deref(), Some("6"));
+        assert_eq!(reports[0].status, "ok");
+        assert!(reports[0].parse_ns.is_some() && reports[0].solve_ns.is_some());
+        assert_eq!(reports[1].answer, None);
+        assert_eq!(reports[1].status, "error");
+        assert_eq!(reports[1].error.as_ref().unwrap().message, "Not solved yet");
+        assert_eq!(reports[1].error.as_ref().unwrap().diagnostic, None);
+        assert_eq!(reports[0].input_hash, Some(sha256("1,2,3")));
+
+        let reports = run(Err(&anyhow::anyhow!("No input found")));
+        assert_eq!(reports[1].input_hash, None);
+        assert_eq!(reports[1].parse_ns, None);
+        assert_eq!(reports[1].error.as_ref().unwrap().message, "No input found");
+
+        let reports = run(Ok("1,a"));
+        assert_eq!(reports.len(), 2);
+        for report in reports {
+            assert!(report.parse_ns.is_some());
+            assert_eq!(report.solve_ns, None);
+            let diagnostic = report.error.unwrap().diagnostic;
+            assert_eq!(diagnostic.map(|d| (d.line, d.column)), Some((1, 2)));
+        }
+    }
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use std::{fs, path::Path};
+
+use anyhow::Context;
+
+/// Inserts `line` just before the line holding `marker`, unless the file already has it.
+///
+/// Returns whether the file was changed.
+fn insert_before_marker(path: &Path, marker: &str, line: &str) -> anyhow::Result<bool> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))?;
+    if content
+        .lines()
+        .any(|existing| existing.trim() == line.trim())
+    {
+        return Ok(false);
+    }
+
+    let (before, after) = content
+        .find(marker)
+        .map(|marker| content.split_at(content[..marker].rfind('\n').map_or(0, |n| n + 1)))
+        .with_context(|| format!("No {:?} marker in {}", marker, path.display()))?;
+    fs::write(path, format!("{}{}\n{}", before, line, after))
+        .with_context(|| format!("Unable to write {}", path.display()))?;
+
+    Ok(true)
+}
+
+/// Creates the crate of a new day in the workspace at `root` from its `template` crate, then
+/// adds it to the workspace members and to the runner's registry.
+///
+/// An existing day is never overwritten, while registering again an already registered day is a
+/// no-op.
+pub fn new_day(root: &Path, number: u8) -> anyhow::Result<()> {
+    let name = format!("day{}", number);
+    let dir = root.join(&name);
+    if dir.exists() {
+        anyhow::bail!("{} already exists", dir.display());
+    }
+
+    let template = root.join("template");
+    let manifest = fs::read_to_string(template.join("Cargo.toml"))?
+        .replace("name = \"template\"", &format!("name = \"{}\"", name));
+    let lib = fs::read_to_string(template.join("src/lib.rs"))?
+        .replace("Template", &format!("Day{}", number))
+        .replace("register_day!(0,", &format!("register_day!({},", number));
+
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("examples"))?;
+    fs::write(dir.join("Cargo.toml"), manifest)?;
+    fs::copy(template.join("build.rs"), dir.join("build.rs"))?;
+    fs::write(dir.join("src/lib.rs"), lib)?;
+    fs::write(dir.join("examples/.gitkeep"), "")?;
+
+    insert_before_marker(
+        &root.join("Cargo.toml"),
+        "## INSERT HERE",
+        &format!("    \"{}\",", name),
+    )?;
+    insert_before_marker(
+        &root.join("aoc/Cargo.toml"),
+        "## INSERT HERE",
+        &format!("{0} = {{ path = \"../{0}\" }}", name),
+    )?;
+    insert_before_marker(
+        &root.join("aoc/src/registry.rs"),
+        "// INSERT HERE",
+        &format!("    {}::DAY,", name),
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::new_day;
+
+    #[test]
+    fn test_new_day() -> anyhow::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("template/src"))?;
+        fs::create_dir_all(root.join("aoc/src"))?;
+        fs::write(
+            root.join("template/Cargo.toml"),
+            "[package]\nname = \"template\"\n",
+        )?;
+        fs::write(root.join("template/build.rs"), "fn main() {}\n")?;
+        fs::write(
+            root.join("template/src/lib.rs"),
+            "pub struct Template;\ncommon::register_day!(0, Template);\n",
+        )?;
+        fs::write(
+            root.join("Cargo.toml"),
+            "members = [\n    \"day1\",\n    ## INSERT HERE\n    \"aoc\",\n]\n",
+        )?;
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\n## INSERT HERE\n",
+        )?;
+        // Registered by hand already, it must not be duplicated
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "&[\n    day1::DAY,\n    day19::DAY,\n    // INSERT HERE\n];\n",
+        )?;
+
+        new_day(&root, 19)?;
+        let read = |path: &str| fs::read_to_string(root.join(path));
+
+        assert_eq!(read("day19/Cargo.toml")?, "[package]\nname = \"day19\"\n");
+        assert_eq!(
+            read("day19/src/lib.rs")?,
+            "pub struct Day19;\ncommon::register_day!(19, Day19);\n"
+        );
+        assert_eq!(read("day19/build.rs")?, "fn main() {}\n");
+        assert!(!root.join("day19/input.txt").exists());
+        assert!(root.join("day19/examples").is_dir());
+        assert_eq!(
+            read("Cargo.toml")?,
+            "members = [\n    \"day1\",\n    \"day19\",\n    ## INSERT HERE\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(
+            read("aoc/Cargo.toml")?,
+            "[dependencies]\nday19 = { path = \"../day19\" }\n## INSERT HERE\n"
+        );
+        assert_eq!(
+            read("aoc/src/registry.rs")?,
+            "&[\n    day1::DAY,\n    day19::DAY,\n    // INSERT HERE\n];\n"
+        );
+        assert!(new_day(&root, 19).is_err());
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
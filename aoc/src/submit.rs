@@ -0,0 +1,315 @@
+use std::{fmt, fs, io, path::Path, str::FromStr};
+
+use anyhow::Context;
+use common::Part;
+use itertools::Itertools;
+
+use crate::client::{Client, YEAR};
+
+/// Verdict of the puzzle website on a submitted answer.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after a wrong answer, with the remaining delay as worded by the website.
+    Wait(String),
+    /// The part was solved before, the answer was not checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    /// Extracts the verdict from the page answering a submission.
+    pub fn from_response(page: &str) -> anyhow::Result<Self> {
+        Ok(if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("some time", |(wait, _)| wait);
+            Outcome::Wait(wait.to_owned())
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            anyhow::bail!("Unrecognised answer from the website")
+        })
+    }
+
+    /// Whether the outcome is a verdict on the answer, worth remembering.
+    fn is_verdict(&self) -> bool {
+        !matches!(self, Outcome::Wait(_) | Outcome::AlreadySolved)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(wait) => write!(f, "wait {}", wait),
+            Outcome::AlreadySolved => write!(f, "already-solved"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            _ => anyhow::bail!("Unknown outcome {:?}", s),
+        }
+    }
+}
+
+/// Past submission and its verdict.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Guess {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every verdict received so far, one `day part answer outcome` line per guess.
+#[derive(Default, Debug)]
+pub struct GuessLog(Vec<Guess>);
+
+impl GuessLog {
+    /// Loads the log, a missing file meaning nothing was submitted yet.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("Unable to read {}", path.display())),
+        };
+
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let (day, part, answer, outcome) = line
+                    .split_whitespace()
+                    .collect_tuple()
+                    .with_context(|| format!("Malformed guess {:?}", line))?;
+
+                Ok(Guess {
+                    day: day.trim_start_matches("day").parse()?,
+                    part: part.parse()?,
+                    answer: answer.to_owned(),
+                    outcome: outcome.parse()?,
+                })
+            })
+            .collect::<anyhow::Result<_>>()
+            .map(GuessLog)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let content = self
+            .0
+            .iter()
+            .map(|guess| {
+                format!(
+                    "day{} {} {} {}\n",
+                    guess.day, guess.part, guess.answer, guess.outcome
+                )
+            })
+            .collect::<String>();
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content).with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    /// Rejects an answer the log already proves wrong, before it costs a submission.
+    ///
+    /// Answers are whitespace separated in the log, so ones which are empty or contain whitespace
+    /// are rejected as well: no puzzle answer looks like that, and they couldn't be logged.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> anyhow::Result<()> {
+        if answer.is_empty() || answer.contains(char::is_whitespace) {
+            anyhow::bail!(
+                "{:?} is not a valid answer, it can't be empty or contain spaces",
+                answer
+            );
+        }
+        let value = answer.parse::<i128>().ok();
+
+        for guess in self.0.iter().filter(|g| g.day == day && g.part == part) {
+            if guess.outcome == Outcome::Correct {
+                anyhow::bail!(
+                    "day{} {} was already solved with {}",
+                    day,
+                    part,
+                    guess.answer
+                );
+            }
+            if guess.answer == answer {
+                anyhow::bail!("{} was already submitted, it was {}", answer, guess.outcome);
+            }
+
+            let previous = guess.answer.parse::<i128>().ok();
+            match (&guess.outcome, value, previous) {
+                (Outcome::TooHigh, Some(value), Some(previous)) if value >= previous => {
+                    anyhow::bail!("{} is not below {}, which was too high", answer, previous)
+                }
+                (Outcome::TooLow, Some(value), Some(previous)) if value <= previous => {
+                    anyhow::bail!("{} is not above {}, which was too low", answer, previous)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn record(&mut self, guess: Guess) {
+        self.0.push(guess);
+    }
+}
+
+/// Submits an answer unless the guess log rules it out, logging the verdict.
+pub fn submit(
+    client: &Client,
+    log_path: &Path,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<Outcome> {
+    let mut log = GuessLog::load(log_path)?;
+    log.check(day, part, answer)?;
+
+    let level = match part {
+        Part::Part1 => "1",
+        Part::Part2 => "2",
+    };
+    let page = client.post_form(
+        &format!("/{}/day/{}/answer", YEAR, day),
+        &[("level", level), ("answer", answer)],
+    )?;
+    let outcome = Outcome::from_response(&page)?;
+
+    if outcome.is_verdict() {
+        log.record(Guess {
+            day,
+            part,
+            answer: answer.to_owned(),
+            outcome: outcome.clone(),
+        });
+        log.save(log_path)?;
+    }
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Part;
+
+    use super::{submit, Guess, GuessLog, Outcome};
+    use crate::client::{test_server, Client};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer \
+        to finding the sleigh keys.</p></article>";
+
+    #[test]
+    fn test_outcome() -> anyhow::Result<()> {
+        let wait = "<article><p>You gave an answer too recently; you have to wait after \
+            submitting an answer before trying again.  You have 43s left to wait.</p></article>";
+        let expected = [
+            (CORRECT, Outcome::Correct),
+            (TOO_HIGH, Outcome::TooHigh),
+            (
+                "<p>That's not the right answer; your answer is too low.</p>",
+                Outcome::TooLow,
+            ),
+            ("<p>That's not the right answer.</p>", Outcome::Wrong),
+            (wait, Outcome::Wait("43s".into())),
+            (
+                "<p>You don't seem to be solving the right level.</p>",
+                Outcome::AlreadySolved,
+            ),
+        ];
+
+        for (page, outcome) in expected {
+            assert_eq!(Outcome::from_response(page)?, outcome, "For page {}", page);
+        }
+        assert!(Outcome::from_response("<html></html>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_guess_log_check() {
+        let mut log = GuessLog::default();
+        let guess = |answer: &str, outcome| Guess {
+            day: 13,
+            part: Part::Part1,
+            answer: answer.into(),
+            outcome,
+        };
+        log.record(guess("983", Outcome::TooHigh));
+        log.record(guess("766", Outcome::TooLow));
+        log.record(guess("ABCD", Outcome::Wrong));
+
+        assert!(log.check(13, Part::Part1, "810").is_ok());
+        assert!(log.check(13, Part::Part1, "983").is_err());
+        assert!(log.check(13, Part::Part1, "1000").is_err());
+        assert!(log.check(13, Part::Part1, "766").is_err());
+        assert!(log.check(13, Part::Part1, "12").is_err());
+        assert!(log.check(13, Part::Part1, "ABCD").is_err());
+        assert!(log.check(13, Part::Part2, "1000").is_ok());
+        assert!(log.check(14, Part::Part1, "1000").is_ok());
+        assert!(log.check(14, Part::Part1, "10 00").is_err());
+        assert!(log.check(14, Part::Part1, "").is_err());
+
+        log.record(guess("810", Outcome::Correct));
+        assert!(log.check(13, Part::Part1, "811").is_err());
+    }
+
+    #[test]
+    fn test_submit() -> anyhow::Result<()> {
+        let (config, mut server) =
+            test_server::serve(vec![(200, TOO_HIGH.into()), (200, CORRECT.into())]);
+        let log_path = config.state_dir.join("guesses.txt");
+        let client = Client::new(config);
+
+        assert_eq!(
+            submit(&client, &log_path, 13, Part::Part1, "983")?,
+            Outcome::TooHigh
+        );
+        // Ruled out by the log, the server never sees it
+        assert!(submit(&client, &log_path, 13, Part::Part1, "1000").is_err());
+        assert_eq!(
+            submit(&client, &log_path, 13, Part::Part1, "810")?,
+            Outcome::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].head.starts_with("POST /2021/day/13/answer "));
+        assert_eq!(requests[0].header("Cookie"), Some("session=cafebabe"));
+        assert_eq!(requests[0].body, "level=1&answer=983");
+        assert_eq!(requests[1].body, "level=1&answer=810");
+
+        let log = GuessLog::load(&log_path)?;
+        assert_eq!(log.0.len(), 2);
+        assert_eq!(log.0[1].outcome, Outcome::Correct);
+        Ok(())
+    }
+}
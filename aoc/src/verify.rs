@@ -0,0 +1,93 @@
+use common::{Day, Part};
+
+use crate::{
+    answers::{Answers, Recorded, Status},
+    input,
+};
+
+/// Runs the given days on their input and compares every part against the recorded answers.
+///
+/// With `record`, outputs of parts without a confirmed answer are written to `answers.toml`. Days
+/// with neither an input nor recorded answers, such as one just started, are skipped.
+pub fn verify(days: &[Day], record: bool) -> anyhow::Result<()> {
+    let mut failures = 0;
+
+    for day in days {
+        let mut answers = Answers::load(day)?;
+        let mut updated = false;
+
+        if answers.is_empty() && !input::exists(day) {
+            println!(
+                "day{:<2} input  skipped, no input nor recorded answers",
+                day.number
+            );
+            continue;
+        }
+
+        let input = match input::read(day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                failures += 1;
+                println!("day{:<2} input  ERROR {:#}", day.number, e);
+                continue;
+            }
+        };
+        let outputs = day.parse(&input).map(|parsed| {
+            Part::ALL
+                .into_iter()
+                .map(|part| (part, parsed.solve(part)))
+                .collect::<Vec<_>>()
+        });
+        let outputs = match outputs {
+            Ok(outputs) => outputs,
+            Err(e) => {
+                failures += 1;
+                println!("day{:<2} parse  ERROR {:#}", day.number, e);
+                continue;
+            }
+        };
+
+        for (part, output) in outputs {
+            let output = match output {
+                Ok(output) => output.to_string(),
+                Err(e) => {
+                    failures += 1;
+                    println!("day{:<2} {}  ERROR {:#}", day.number, part, e);
+                    continue;
+                }
+            };
+
+            let status = Status::check(answers.get(part), &output);
+            if matches!(status, Status::Mismatch { .. }) {
+                failures += 1;
+            }
+            println!(
+                "day{:<2} {}  {}",
+                day.number,
+                part,
+                status.describe(&output)
+            );
+
+            if record && matches!(status, Status::Changed { .. } | Status::Missing) {
+                answers.set(
+                    part,
+                    Recorded {
+                        answer: output,
+                        confirmed: false,
+                    },
+                );
+                updated = true;
+            }
+        }
+
+        if updated {
+            answers.save(day)?;
+        }
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{} part(s) failed verification", failures);
+    }
+
+    Ok(())
+}
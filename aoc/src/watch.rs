@@ -0,0 +1,317 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Arc,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use common::{Day, Part};
+
+use crate::{
+    answers::{Answers, Status},
+    client, input, pool,
+};
+
+/// Modification time of every watched file.
+type Snapshot = BTreeMap<PathBuf, Option<SystemTime>>;
+
+/// Every file under `path`, which may be a single file.
+fn files(path: &Path, out: &mut Vec<PathBuf>) {
+    match fs::read_dir(path) {
+        Ok(entries) => {
+            for entry in entries.flatten() {
+                files(&entry.path(), out);
+            }
+        }
+        Err(_) => out.push(path.to_owned()),
+    }
+}
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut all = Vec::new();
+    for path in paths {
+        files(path, &mut all);
+    }
+    all.into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Answer of a solved part, or the line reporting why there is none. Timeouts are reported with
+/// the `timeout` given rather than the time elapsed, so they read the same from one run to the next.
+fn answer(outcome: &pool::Outcome, timeout: Duration) -> Result<String, String> {
+    match &outcome.status {
+        pool::Status::Solved(answer) => Ok(answer.to_string()),
+        pool::Status::Failed(error) => Err(format!("ERROR {}", error.message)),
+        pool::Status::Panicked(message) => Err(format!("PANIC {}", message)),
+        pool::Status::TimedOut => Err(format!("TIMEOUT after {:?}", timeout)),
+    }
+}
+
+/// What a solved part is checked against.
+enum Expected {
+    Example(String),
+    /// The answer recorded for the part, if any.
+    Input,
+}
+
+/// Runs the examples of a day then its input, one line per check keyed by what was run.
+///
+/// Solvers go through the pool, so one which panics or never returns while being written doesn't
+/// stop the watch. One still running after `timeout` is reported and left running.
+fn evaluate(day: &Day, timeout: Duration) -> Vec<(String, String)> {
+    let mut lines = Vec::new();
+    let mut jobs = Vec::new();
+    let mut checks = Vec::new();
+
+    let mut examples = Vec::new();
+    files(&Path::new(day.dir).join("examples"), &mut examples);
+    examples.retain(|path| path.extension().is_some_and(|ext| ext == "txt"));
+    examples.sort();
+    for path in examples {
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let example = match fs::read_to_string(&path) {
+            Ok(example) => example,
+            Err(e) => {
+                lines.push((format!("example {}", name), format!("ERROR {}", e)));
+                continue;
+            }
+        };
+        let mut parts = Vec::new();
+        for part in Part::ALL {
+            if let Ok(expected) = fs::read_to_string(path.with_extension(part.to_string())) {
+                let expected = expected.trim_end_matches(['\n', '\r']).to_owned();
+                checks.push((
+                    format!("example {} {}", name, part),
+                    Expected::Example(expected),
+                ));
+                parts.push(part);
+            }
+        }
+        if !parts.is_empty() {
+            jobs.push(pool::Job {
+                day: *day,
+                parts,
+                input: Ok(Arc::from(example)),
+            });
+        }
+    }
+
+    let answers = Answers::load(day).unwrap_or_default();
+    for part in Part::ALL {
+        checks.push((format!("input {}", part), Expected::Input));
+    }
+    jobs.push(pool::Job {
+        day: *day,
+        parts: Part::ALL.to_vec(),
+        input: input::read(day, None)
+            .map(Arc::from)
+            .map_err(|e| (&e).into()),
+    });
+
+    let options = pool::Options {
+        jobs: thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+        timeout,
+    };
+    for ((key, expected), outcome) in checks.into_iter().zip(pool::run(jobs, options)) {
+        let line = match (answer(&outcome, timeout), expected) {
+            (Ok(output), Expected::Example(expected)) if output == expected => {
+                format!("ok {}", output)
+            }
+            (Ok(output), Expected::Example(expected)) => {
+                format!("FAIL expected {} got {}", expected, output)
+            }
+            (Ok(output), Expected::Input) => {
+                Status::check(answers.get(outcome.part), &output).describe(&output)
+            }
+            (Err(line), _) => line,
+        };
+        lines.push((key, line));
+    }
+
+    lines
+}
+
+/// Report of `current`, with the lines which differ from the `previous` run marked.
+fn diff(previous: Option<&[(String, String)]>, current: &[(String, String)]) -> Vec<String> {
+    let before = previous
+        .unwrap_or_default()
+        .iter()
+        .map(|(key, line)| (key.as_str(), line.as_str()))
+        .collect::<HashMap<_, _>>();
+    let mut report = current
+        .iter()
+        .map(|(key, line)| match before.get(key.as_str()) {
+            _ if previous.is_none() => format!("  {:<24} {}", key, line),
+            None => format!("+ {:<24} {}", key, line),
+            Some(old) if old == line => format!("  {:<24} {}", key, line),
+            Some(old) => format!("* {:<24} {} (was {})", key, line, old),
+        })
+        .collect::<Vec<_>>();
+    report.extend(
+        previous
+            .unwrap_or_default()
+            .iter()
+            .filter(|(key, _)| !current.iter().any(|(other, _)| other == key))
+            .map(|(key, line)| format!("- {:<24} {}", key, line)),
+    );
+    report
+}
+
+/// Rebuilds the runner, returning whether it succeeded.
+fn rebuild() -> anyhow::Result<bool> {
+    let mut cargo = Command::new(option_env!("CARGO").unwrap_or("cargo"));
+    cargo
+        .args(["build", "-p", "aoc"])
+        .current_dir(client::workspace_root());
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    Ok(cargo.status()?.success())
+}
+
+/// Replaces the running runner by the freshly built `exe`, with the same arguments.
+#[cfg(unix)]
+fn restart(exe: &Path) -> anyhow::Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    Err(Command::new(exe).args(env::args_os().skip(1)).exec().into())
+}
+
+#[cfg(not(unix))]
+fn restart(_exe: &Path) -> anyhow::Result<()> {
+    anyhow::bail!("Rebuilt, start the watch again to run the new code")
+}
+
+/// Reruns the examples and input of `day` whenever they change, checking for changes every
+/// `interval` and giving each part `timeout` to finish. A change to the day's sources rebuilds the
+/// runner and restarts it.
+pub fn watch(day: &Day, interval: Duration, timeout: Duration) -> anyhow::Result<()> {
+    // Resolved before any rebuild replaces the binary
+    let exe = env::current_exe()?;
+    let dir = Path::new(day.dir);
+    let sources = [dir.join("src"), dir.join("Cargo.toml")];
+    let mut data = vec![dir.join("examples"), Answers::path(day)];
+    data.extend(input::candidates(day));
+
+    let mut watched = (snapshot(&sources), snapshot(&data));
+    let mut last: Option<Vec<(String, String)>> = None;
+    loop {
+        let current = evaluate(day, timeout);
+        println!("--- day{}", day.number);
+        for line in diff(last.as_deref(), &current) {
+            println!("{}", line);
+        }
+        last = Some(current);
+
+        loop {
+            thread::sleep(interval);
+            let (sources_before, data_before) = &watched;
+            let now = (snapshot(&sources), snapshot(&data));
+            if now.0 != *sources_before {
+                println!("--- day{} sources changed, rebuilding", day.number);
+                if rebuild()? {
+                    return restart(&exe);
+                }
+                watched.0 = now.0;
+            } else if now.1 != *data_before {
+                watched = now;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::Part;
+
+    use super::{answer, diff};
+    use crate::{
+        pool::{Outcome, Status},
+        report::Error,
+    };
+
+    fn lines(lines: &[(&str, &str)]) -> Vec<(String, String)> {
+        lines
+            .iter()
+            .map(|(key, line)| (key.to_string(), line.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = lines(&[("example small part1", "ok 10"), ("input part1", "ok 3856")]);
+        let second = lines(&[
+            ("example small part1", "ok 10"),
+            ("example small part2", "ok 36"),
+            ("input", "ERROR No input found"),
+        ]);
+
+        assert_eq!(
+            diff(None, &first),
+            [
+                "  example small part1      ok 10",
+                "  input part1              ok 3856",
+            ]
+        );
+        assert_eq!(
+            diff(Some(&first), &second),
+            [
+                "  example small part1      ok 10",
+                "+ example small part2      ok 36",
+                "+ input                    ERROR No input found",
+                "- input part1              ok 3856",
+            ]
+        );
+        assert_eq!(
+            diff(Some(&second), &lines(&[("input", "ERROR Bad input")]))[0],
+            "* input                    ERROR Bad input (was ERROR No input found)"
+        );
+    }
+
+    #[test]
+    fn test_answer() {
+        let outcome = |status| Outcome {
+            day: 12,
+            part: Part::Part2,
+            status,
+            parse: None,
+            solve: None,
+            elapsed: Duration::from_millis(250),
+        };
+        let timeout = Duration::from_millis(200);
+        let error = Error {
+            message: "No path".to_owned(),
+            diagnostic: None,
+        };
+
+        assert_eq!(
+            answer(&outcome(Status::Solved(36u32.into())), timeout),
+            Ok("36".to_owned())
+        );
+        assert_eq!(
+            answer(&outcome(Status::Failed(error)), timeout),
+            Err("ERROR No path".to_owned())
+        );
+        assert_eq!(
+            answer(
+                &outcome(Status::Panicked("Lost in the caves".to_owned())),
+                timeout
+            ),
+            Err("PANIC Lost in the caves".to_owned())
+        );
+        assert_eq!(
+            answer(&outcome(Status::TimedOut), timeout),
+            Err("TIMEOUT after 200ms".to_owned())
+        );
+    }
+}
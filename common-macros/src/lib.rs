@@ -0,0 +1,80 @@
+use std::{env, fs, path::Path};
+
+use proc_macro::TokenStream;
+
+/// Turns the stem of an example file into a valid test name.
+fn test_name(stem: &str, part: &str) -> String {
+    let stem = stem
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    if stem.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("example_{}_{}", stem, part)
+    } else {
+        format!("{}_{}", stem, part)
+    }
+}
+
+/// Generates one test per example and part of the calling day crate.
+///
+/// Every `examples/<name>.txt` in the crate is an input, checked against the expected answers in
+/// `examples/<name>.part1` and `examples/<name>.part2` when they exist, through the crate's `DAY`
+/// registry entry. The crate's build script must ask cargo to rerun when `examples` changes so
+/// new files are picked up.
+#[proc_macro]
+pub fn examples(input: TokenStream) -> TokenStream {
+    if !input.is_empty() {
+        return r#"compile_error!("examples!() takes no arguments");"#
+            .parse()
+            .unwrap();
+    }
+
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("examples");
+    let mut inputs = fs::read_dir(&dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    inputs.sort();
+
+    let mut tests = String::new();
+    for input in inputs {
+        let stem = input.file_stem().unwrap().to_string_lossy();
+        for (part, variant) in [("part1", "Part1"), ("part2", "Part2")] {
+            let answer = input.with_extension(part);
+            if !answer.exists() {
+                continue;
+            }
+
+            tests += &format!(
+                r#"
+                #[test]
+                fn {name}() -> anyhow::Result<()> {{
+                    let output = crate::DAY.run(::common::Part::{variant}, include_str!({input:?}))?;
+                    let expected = include_str!({answer:?}).trim_end_matches(['\n', '\r']);
+                    assert_eq!(output.to_string(), expected, "For example {stem}");
+                    Ok(())
+                }}
+                "#,
+                name = test_name(&stem, part),
+                variant = variant,
+                input = input.display().to_string(),
+                answer = answer.display().to_string(),
+                stem = stem,
+            );
+        }
+    }
+
+    format!("#[cfg(test)] mod examples {{ {} }}", tests)
+        .parse()
+        .unwrap()
+}
@@ -0,0 +1,74 @@
+use std::fmt;
+
+use crate::Grid;
+
+/// Result of one part of a puzzle, whatever its kind.
+///
+/// # Examples
+///
+/// ```
+/// use common::Answer;
+///
+/// assert_eq!(Answer::from(42usize), Answer::Integer(42));
+/// assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+/// assert_eq!(Answer::from("HLBUBGFR"), Answer::Text("HLBUBGFR".into()));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Answer {
+    Integer(i64),
+    /// Integer too large for an `i64`.
+    BigInteger(i128),
+    Text(String),
+    /// Picture to be read by a human, one string per row.
+    Grid(Vec<String>),
+}
+
+macro_rules! from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::BigInteger(value as i128),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+impl<T: fmt::Display> From<Grid<T>> for Answer {
+    fn from(grid: Grid<T>) -> Self {
+        Answer::Grid(
+            grid.rows()
+                .map(|row| row.iter().map(ToString::to_string).collect())
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::{error::Error, fmt};
+
+use nom_supreme::{
+    error::{BaseErrorKind, ErrorTree, Expectation, StackContext},
+    final_parser::ByteOffset,
+};
+
+/// Failure of [`parse`](crate::parse), located in the input and ready to be shown to a human.
+///
+/// Only the alternatives which got the furthest in the input are kept, they are the ones most
+/// likely to describe what is wrong.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    /// 1-based line of the error.
+    pub line: usize,
+    /// 1-based column of the error, in characters.
+    pub column: usize,
+    /// Content of the offending line.
+    pub source_line: String,
+    /// What the parser would have accepted at that point.
+    pub expected: Vec<String>,
+    /// Errors raised while converting the input, such as a number out of range.
+    pub errors: Vec<String>,
+    /// Sections being parsed, innermost first, as named with `.context()`.
+    pub contexts: Vec<&'static str>,
+}
+
+struct Leaf<'t> {
+    offset: usize,
+    kind: &'t BaseErrorKind,
+    contexts: Vec<&'static str>,
+}
+
+fn leaves<'t>(tree: &'t ErrorTree<ByteOffset>, contexts: &[&'static str], out: &mut Vec<Leaf<'t>>) {
+    match tree {
+        ErrorTree::Base { location, kind } => out.push(Leaf {
+            offset: location.0,
+            kind,
+            contexts: contexts.to_vec(),
+        }),
+        ErrorTree::Stack {
+            base,
+            contexts: stack,
+        } => {
+            let named = stack.iter().filter_map(|(_, context)| match context {
+                StackContext::Context(name) => Some(*name),
+                StackContext::Kind(_) => None,
+            });
+            let inner = named.chain(contexts.iter().copied()).collect::<Vec<_>>();
+            leaves(base, &inner, out)
+        }
+        ErrorTree::Alt(alternatives) => {
+            for alternative in alternatives {
+                leaves(alternative, contexts, out)
+            }
+        }
+    }
+}
+
+impl ParseError {
+    pub fn new(input: &str, tree: &ErrorTree<ByteOffset>) -> Self {
+        let mut all = Vec::new();
+        leaves(tree, &[], &mut all);
+
+        let offset = all.iter().map(|leaf| leaf.offset).max().unwrap_or(0);
+        let furthest = all.iter().filter(|leaf| leaf.offset == offset);
+
+        let mut expected = Vec::new();
+        let mut errors = Vec::new();
+        for leaf in furthest.clone() {
+            let (list, description) = match leaf.kind {
+                BaseErrorKind::Expected(Expectation::Eof) => (&mut expected, "end of input".into()),
+                BaseErrorKind::Expected(Expectation::Something) => {
+                    (&mut expected, "more input".into())
+                }
+                BaseErrorKind::Expected(expectation) => (&mut expected, expectation.to_string()),
+                BaseErrorKind::Kind(kind) => (&mut expected, kind.description().to_lowercase()),
+                BaseErrorKind::External(error) => (&mut errors, error.to_string()),
+            };
+            if !list.contains(&description) {
+                list.push(description);
+            }
+        }
+
+        let (before, after) = input.split_at(offset.min(input.len()));
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        let line_end = after
+            .find('\n')
+            .map_or(input.len(), |newline| offset + newline);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            expected,
+            errors,
+            contexts: furthest
+                .map(|leaf| leaf.contexts.clone())
+                .next()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match (self.expected.as_slice(), self.errors.as_slice()) {
+            (_, [error, ..]) => write!(f, "{}", error)?,
+            ([expected], []) => write!(f, "expected {}", expected)?,
+            (expected, []) => write!(f, "expected one of {}", expected.join(", "))?,
+        }
+        if !self.contexts.is_empty() {
+            write!(f, " (in {})", self.contexts.join(" in "))?;
+        }
+
+        let margin = " ".repeat(self.line.to_string().len());
+        writeln!(f)?;
+        writeln!(f, "{} |", margin)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{} | {}^", margin, " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete::{digit1, newline, space1},
+        multi::separated_list1,
+        sequence::separated_pair,
+    };
+    use nom_supreme::{parse_from_str, ParserExt};
+
+    crate::nom_enum!(
+        enum Movement {
+            Forward = "forward",
+            Down = "down",
+            Up = "up",
+        }
+    );
+
+    fn course(input: &str) -> Result<Vec<(Movement, u8)>, super::ParseError> {
+        let movement = separated_pair(Movement::parser(), space1, parse_from_str(digit1));
+        crate::parse(
+            input,
+            separated_list1(newline, movement.context("movement")),
+        )
+    }
+
+    #[test]
+    fn test_expected_alternatives() {
+        let error = course("sideways 3\ndown 5").unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.source_line, "sideways 3");
+        assert_eq!(
+            error.to_string(),
+            r#"line 1, column 1: expected one of "forward", "down", "up" (in movement)
+  |
+1 | sideways 3
+  | ^"#
+        );
+    }
+
+    #[test]
+    fn test_trailing_input() {
+        let error = course("forward 5\ndown 5\nsideways 3").unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 7));
+        assert_eq!(error.expected, ["end of input"]);
+    }
+
+    #[test]
+    fn test_external_error() {
+        let error = course("down 500\nup 3").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: number too large to fit in target type (in movement)\n  |\n1 | down 500\n  |      ^"
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    error::Error,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use nom::{
+    character::complete::{anychar, newline},
+    combinator::{map_opt, map_res},
+    error::{FromExternalError, ParseError},
+    multi::{many1, separated_list1},
+    Parser,
+};
+
+/// Position in a [`Grid`], as `(row, column)`.
+pub type Point = (usize, usize);
+
+/// Rectangular 2D map stored row by row.
+///
+/// # Examples
+///
+/// ```
+/// use common::Grid;
+///
+/// let grid: Grid<u32> = common::parse("219\n398", Grid::digits()).unwrap();
+///
+/// assert_eq!((grid.height(), grid.width()), (2, 3));
+/// assert_eq!(grid[(1, 2)], 8);
+/// assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+/// assert_eq!(grid.transpose().to_string(), "23\n19\n98");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    cells: Vec<T>,
+}
+
+/// Rows given to [`Grid::from_rows`] don't all have the same length.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RaggedRows {
+    pub row: usize,
+    pub length: usize,
+    pub expected: usize,
+}
+
+impl fmt::Display for RaggedRows {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Row {} has {} cells where {} were expected",
+            self.row, self.length, self.expected
+        )
+    }
+}
+
+impl Error for RaggedRows {}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRows> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(RaggedRows {
+                row,
+                length: cells.len(),
+                expected: width,
+            });
+        }
+
+        Ok(Grid {
+            width,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, each cell with `cell`.
+    pub fn parser<'i, E>(cell: impl Parser<&'i str, T, E>) -> impl Parser<&'i str, Self, E>
+    where
+        E: ParseError<&'i str> + FromExternalError<&'i str, RaggedRows>,
+    {
+        map_res(separated_list1(newline, many1(cell)), Self::from_rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len().checked_div(self.width).unwrap_or(0)
+    }
+
+    pub fn contains(&self, (row, column): Point) -> bool {
+        row < self.height() && column < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.0 * self.width + point.1])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.0 * self.width + point.1])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Positions above, left, below and right of `point` which are in the grid.
+    pub fn neighbours4(&self, (row, column): Point) -> impl Iterator<Item = Point> + '_ {
+        [(-1, 0), (0, -1), (1, 0), (0, 1)]
+            .into_iter()
+            .filter_map(move |offset| self.offset((row, column), offset))
+    }
+
+    /// Positions around `point`, diagonals included, which are in the grid.
+    pub fn neighbours8(&self, (row, column): Point) -> impl Iterator<Item = Point> + '_ {
+        [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ]
+        .into_iter()
+        .filter_map(move |offset| self.offset((row, column), offset))
+    }
+
+    fn offset(&self, (row, column): Point, (i, j): (isize, isize)) -> Option<Point> {
+        let point = (row.checked_add_signed(i)?, column.checked_add_signed(j)?);
+        self.contains(point).then_some(point)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of `column` from top to bottom, none when it is outside of the grid.
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let height = if column < self.width {
+            self.height()
+        } else {
+            0
+        };
+        self.cells
+            .iter()
+            .skip(column)
+            .step_by(self.width.max(1))
+            .take(height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height(),
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+
+    /// Repeats the grid `rows` times vertically and `columns` times horizontally, each copy's
+    /// cells computed by `f` from the original cell and the `(row, column)` of the copy.
+    pub fn tile(&self, rows: usize, columns: usize, mut f: impl FnMut(&T, Point) -> T) -> Self {
+        let width = self.width * columns;
+        let cells = (0..self.height() * rows)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let cell = &self[(row % self.height(), column % self.width)];
+                f(cell, (row / self.height(), column / self.width))
+            })
+            .collect();
+
+        Grid { width, cells }
+    }
+}
+
+impl Grid<u32> {
+    /// Parses a grid of single digit cells.
+    pub fn digits<'i, E>() -> impl Parser<&'i str, Self, E>
+    where
+        E: ParseError<&'i str> + FromExternalError<&'i str, RaggedRows>,
+    {
+        Self::parser(map_opt(anychar, |c| c.to_digit(10)))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).expect("Point out of the grid")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point).expect("Point out of the grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Grid, RaggedRows};
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            [(1, 1), (1, 2), (2, 1)]
+        );
+        assert_eq!(grid.get((3, 0)), None);
+    }
+
+    #[test]
+    fn test_tile() {
+        let grid = Grid::from_rows(vec![vec![8]]).unwrap();
+        let tiled = grid.tile(2, 3, |cell, (i, j)| (cell + i + j - 1) % 9 + 1);
+
+        assert_eq!(tiled.to_string(), "891\n912");
+        assert_eq!(tiled.column(2).collect::<Vec<_>>(), [&1, &2]);
+        assert_eq!(tiled.column(3).count(), 0);
+        assert_eq!(tiled.column(4).count(), 0);
+    }
+
+    #[test]
+    fn test_ragged() {
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3]]),
+            Err(RaggedRows {
+                row: 1,
+                length: 1,
+                expected: 2
+            })
+        );
+        assert!(crate::parse::<Grid<u32>>("12\n3", Grid::digits()).is_err());
+    }
+}
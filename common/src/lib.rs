@@ -1,24 +1,44 @@
+mod answer;
+mod diagnostic;
+mod grid;
+pub mod ocr;
+pub mod rotation;
+pub mod search;
+mod solution;
+pub mod trace;
+
+pub use answer::Answer;
+pub use common_macros::examples;
+pub use diagnostic::ParseError;
+pub use grid::{Grid, Point, RaggedRows};
+pub use solution::{Day, Parsed, Part, Puzzle, Solution, SolutionPuzzle};
+
 use nom::Parser;
 use nom_supreme::{
     error::ErrorTree,
-    final_parser::{final_parser, Location},
+    final_parser::{final_parser, ByteOffset},
 };
 
 /// Simple helper function, exists solely to reduce boilerplate caused by having to specify generic
-/// types.
+/// types. Errors are rendered against the input as a [`ParseError`].
 pub fn parse<'i, O>(
     input: &'i str,
     parser: impl Parser<&'i str, O, ErrorTree<&'i str>>,
-) -> Result<O, ErrorTree<Location>> {
-    final_parser(parser)(input)
+) -> Result<O, ParseError> {
+    final_parser(parser)(input).map_err(|tree: ErrorTree<ByteOffset>| ParseError::new(input, &tree))
 }
 
 /// Simple dataless enum with an auto generated nom parser
 ///
+/// The enum takes the given visibility and attributes, and `Display`/`FromStr` implementations
+/// using the same literals. When literals share a prefix the longest one is tried first, and a
+/// leading `#[nom_enum(case_insensitive)]` makes the parser ignore case.
+///
 /// # Examples
 ///
 /// ```
 /// use nom::{error::ParseError, Parser};
+/// use nom_supreme::tag::TagError;
 ///
 /// common::nom_enum!(
 ///     enum Sample {
@@ -28,24 +48,141 @@ pub fn parse<'i, O>(
 ///     }
 /// );
 ///
-/// fn puzzle<'i, E: ParseError<&'i str>>(input: &'i str) -> nom::IResult<&'i str, Sample, E> {
+/// fn puzzle<'i, E>(input: &'i str) -> nom::IResult<&'i str, Sample, E>
+/// where
+///     E: ParseError<&'i str> + TagError<&'i str, &'static str>,
+/// {
 ///     Sample::parser().parse(input)
 /// }
+///
+/// common::nom_enum!(
+///     #[nom_enum(case_insensitive)]
+///     /// Unit of a quantity
+///     pub enum Unit {
+///         Meter = "m",
+///         Millimeter = "mm",
+///     }
+/// );
+///
+/// assert_eq!("MM".parse::<Unit>().unwrap(), Unit::Millimeter);
+/// assert_eq!(Unit::Meter.to_string(), "m");
+/// assert_eq!(Unit::ALL, [Unit::Meter, Unit::Millimeter]);
 /// ```
 #[macro_export]
 macro_rules! nom_enum {
-    (enum $type:ident {$($variant:ident = $alias:literal,)*}) => {
+    (@impl $tag:ident;
+        $(#[$meta:meta])*
+        $vis:vis enum $type:ident {$($(#[$variant_meta:meta])* $variant:ident = $alias:literal,)*}
+    ) => {
+        $(#[$meta])*
         #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-        enum $type {
-            $($variant),*
+        $vis enum $type {
+            $($(#[$variant_meta])* $variant),*
         }
 
+        #[allow(dead_code)]
         impl $type {
-            fn parser<'i, E: nom::error::ParseError<&'i str>>() -> impl nom::Parser<&'i str, Self, E> {
-                nom::branch::alt((
-                    $(nom::combinator::value(Self::$variant, nom::bytes::complete::tag($alias))),*
-                ))
+            $vis const ALL: &'static [Self] = &[$(Self::$variant),*];
+
+            $vis fn parser<'i, E>() -> impl nom::Parser<&'i str, Self, E>
+            where
+                E: nom::error::ParseError<&'i str>
+                    + nom_supreme::tag::TagError<&'i str, &'static str>,
+            {
+                let mut candidates = [$((Self::$variant, $alias)),*];
+                candidates.sort_by_key(|(_, alias)| std::cmp::Reverse(alias.len()));
+
+                move |input: &'i str| {
+                    let mut error: Option<E> = None;
+                    for (variant, alias) in candidates {
+                        match nom_supreme::tag::complete::$tag(alias)(input) {
+                            Ok((rest, _)) => return Ok((rest, variant)),
+                            Err(nom::Err::Error(e)) => {
+                                error = Some(match error {
+                                    Some(error) => error.or(e),
+                                    None => e,
+                                })
+                            }
+                            Err(e) => return Err(e),
+                        }
+                    }
+
+                    let error = error.unwrap_or_else(|| {
+                        E::from_error_kind(input, nom::error::ErrorKind::Alt)
+                    });
+                    Err(nom::Err::Error(E::append(input, nom::error::ErrorKind::Alt, error)))
+                }
+            }
+        }
+
+        impl std::fmt::Display for $type {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $alias),*
+                })
             }
         }
+
+        impl std::str::FromStr for $type {
+            type Err = $crate::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::parse(s, Self::parser())
+            }
+        }
+    };
+    (#[nom_enum(case_insensitive)] $($enum:tt)*) => {
+        $crate::nom_enum!(@impl tag_no_case; $($enum)*);
+    };
+    ($($enum:tt)*) => {
+        $crate::nom_enum!(@impl tag; $($enum)*);
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use nom::Parser;
+    use nom_supreme::error::ErrorTree;
+
+    crate::nom_enum!(
+        pub(crate) enum Comparison {
+            Less = "<",
+            LessOrEqual = "<=",
+            Equal = "==",
+        }
+    );
+
+    crate::nom_enum!(
+        #[nom_enum(case_insensitive)]
+        #[derive(PartialOrd, Ord)]
+        enum Direction {
+            North = "n",
+            #[allow(dead_code)]
+            South = "s",
+        }
+    );
+
+    #[test]
+    fn test_nom_enum_longest_match() {
+        let mut parser = Comparison::parser::<ErrorTree<&str>>();
+
+        assert_eq!(parser.parse("<=3").unwrap(), ("3", Comparison::LessOrEqual));
+        assert_eq!(parser.parse("<3").unwrap(), ("3", Comparison::Less));
+        assert!(parser.parse(">3").is_err());
+    }
+
+    #[test]
+    fn test_nom_enum_strings() {
+        assert_eq!(Comparison::ALL.len(), 3);
+        for comparison in Comparison::ALL {
+            assert_eq!(
+                comparison.to_string().parse::<Comparison>().unwrap(),
+                *comparison
+            );
+        }
+        assert!("<>".parse::<Comparison>().is_err());
+
+        assert_eq!("N".parse::<Direction>().unwrap(), Direction::North);
+        assert!(Direction::North < Direction::South);
+    }
+}
@@ -0,0 +1,246 @@
+//! Reading of the block letters some puzzles draw as their answer.
+
+use anyhow::Context;
+
+use crate::{Grid, Point};
+
+/// Letters 4 pixels wide (3 for `I`, 5 for `Y`) and 6 tall.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', "###.#..#..#..#.###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Y', "#...##...#.#.#...#....#....#.."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// Letters 6 pixels wide and 10 tall.
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##...#..#.#....##....##....########....##....##....##....#",
+    ),
+    (
+        'B',
+        "#####.#....##....##....######.#....##....##....##....######.",
+    ),
+    (
+        'C',
+        ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+    ),
+    (
+        'E',
+        "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+    ),
+    (
+        'F',
+        "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+    ),
+    (
+        'G',
+        ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+    ),
+    (
+        'H',
+        "#....##....##....##....########....##....##....##....##....#",
+    ),
+    (
+        'J',
+        "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+    ),
+    (
+        'K',
+        "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+    ),
+    (
+        'L',
+        "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+    ),
+    (
+        'N',
+        "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+    ),
+    (
+        'P',
+        "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+    ),
+    (
+        'R',
+        "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+    ),
+    (
+        'X',
+        "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+    ),
+    (
+        'Z',
+        "######.....#.....#....#....#....#....#....#.....#.....######",
+    ),
+];
+
+/// Renders the pixels of columns `columns` of `grid` as `#` and `.`, row by row.
+fn glyph(grid: &Grid<bool>, columns: &[usize]) -> String {
+    (0..grid.height())
+        .flat_map(|row| columns.iter().map(move |&column| (row, column)))
+        .map(|point| if grid[point] { '#' } else { '.' })
+        .collect()
+}
+
+/// Reads the letters drawn by the `true` pixels of `grid`, in either font.
+///
+/// # Examples
+///
+/// ```
+/// use common::{ocr, Grid};
+///
+/// let picture = "#..#.###.\n#..#..#..\n####..#..\n#..#..#..\n#..#..#..\n#..#.###.";
+/// let grid = common::parse(picture, Grid::parser(nom::character::complete::one_of("#.")))
+///     .unwrap()
+///     .map(|&pixel| pixel == '#');
+///
+/// assert_eq!(ocr::read(&grid).unwrap(), "HI");
+/// ```
+pub fn read(grid: &Grid<bool>) -> anyhow::Result<String> {
+    let lit_rows = (0..grid.height())
+        .filter(|&row| (0..grid.width()).any(|column| grid[(row, column)]))
+        .collect::<Vec<_>>();
+    let (top, bottom) = lit_rows
+        .first()
+        .zip(lit_rows.last())
+        .context("Nothing to read")?;
+    let grid = Grid::from_rows(
+        grid.rows()
+            .skip(*top)
+            .take(bottom - top + 1)
+            .map(<[bool]>::to_vec)
+            .collect(),
+    )?;
+
+    let font = match grid.height() {
+        6 => SMALL,
+        10 => LARGE,
+        height => anyhow::bail!("No font is {} pixels tall", height),
+    };
+
+    // Letters are separated by blank columns
+    let columns = (0..grid.width())
+        .map(|column| grid.column(column).any(|&pixel| pixel))
+        .collect::<Vec<_>>();
+    let letters = columns
+        .split(|lit| !lit)
+        .scan(0, |start, letter| {
+            let range = *start..*start + letter.len();
+            *start = range.end + 1;
+            Some(range)
+        })
+        .filter(|range| !range.is_empty());
+
+    letters
+        .map(|range| {
+            let columns = range.collect::<Vec<_>>();
+            let glyph = glyph(&grid, &columns);
+            font.iter()
+                .find(|(_, pixels)| *pixels == glyph)
+                .map(|(letter, _)| *letter)
+                .with_context(|| {
+                    let rows = glyph.as_bytes().chunks(columns.len());
+                    let drawing = rows.map(String::from_utf8_lossy).collect::<Vec<_>>();
+                    format!("Unknown letter\n{}", drawing.join("\n"))
+                })
+        })
+        .collect()
+}
+
+/// Reads the letters drawn by the lit pixels at `points`.
+pub fn read_points(points: impl IntoIterator<Item = Point>) -> anyhow::Result<String> {
+    let points = points.into_iter().collect::<Vec<_>>();
+    let height = points.iter().map(|&(row, _)| row + 1).max().unwrap_or(0);
+    let width = points
+        .iter()
+        .map(|&(_, column)| column + 1)
+        .max()
+        .unwrap_or(0);
+
+    let mut grid = Grid::new(height, width, false);
+    for point in points {
+        grid[point] = true;
+    }
+    read(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_points, LARGE, SMALL};
+
+    /// Points of a picture drawn with `#`, starting at `(top, left)`.
+    fn points(picture: &str, (top, left): (usize, usize)) -> Vec<(usize, usize)> {
+        picture
+            .lines()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.char_indices()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(column, _)| (top + row, left + column))
+            })
+            .collect()
+    }
+
+    /// Lays out the glyphs of `word` from `font` side by side with `gap` blank columns.
+    fn render(font: &[(char, &str)], word: &str, width: usize, gap: usize) -> String {
+        let glyphs = word
+            .chars()
+            .map(|letter| font.iter().find(|(c, _)| *c == letter).unwrap().1)
+            .collect::<Vec<_>>();
+        let height = font[0].1.len() / width;
+
+        (0..height)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|glyph| {
+                        let width = glyph.len() / height;
+                        &glyph[row * width..(row + 1) * width]
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(gap))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_fonts() -> anyhow::Result<()> {
+        let small = SMALL.iter().map(|(letter, _)| letter).collect::<String>();
+        let large = LARGE.iter().map(|(letter, _)| letter).collect::<String>();
+
+        assert_eq!(
+            read_points(points(&render(SMALL, &small, 4, 1), (2, 3)))?,
+            small
+        );
+        assert_eq!(
+            read_points(points(&render(LARGE, &large, 6, 2), (0, 0)))?,
+            large
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown() {
+        let error = read_points(points("#.#\n.#.\n#.#\n.#.\n#.#\n.#.", (0, 0))).unwrap_err();
+
+        assert!(error.to_string().starts_with("Unknown letter\n#.#\n.#."));
+        assert!(read_points([]).is_err());
+    }
+}
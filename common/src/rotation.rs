@@ -0,0 +1,130 @@
+//! Rotations of space by quarter turns around the axes.
+
+/// Coordinates along the x, y and z axes.
+pub type Vector = [i64; 3];
+
+/// Rotation mapping every axis onto an axis, one of the 24 ways a cube can be oriented.
+///
+/// # Examples
+///
+/// ```
+/// use common::rotation::Rotation;
+///
+/// let rotation = Rotation::ALL[5];
+/// assert_eq!(rotation.inverse().apply(rotation.apply([1, 2, 3])), [1, 2, 3]);
+/// assert_eq!(
+///     Rotation::ALL.iter().filter(|r| r.apply([1, 0, 0]) == [0, 0, 1]).count(),
+///     4
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rotation {
+    /// Axis of the original vector each coordinate is taken from.
+    axes: [usize; 3],
+    /// Sign each coordinate is multiplied by.
+    signs: [i64; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        axes: [0, 1, 2],
+        signs: [1, 1, 1],
+    };
+
+    /// Every rotation, the identity first.
+    pub const ALL: [Rotation; 24] = Self::all();
+
+    const fn all() -> [Rotation; 24] {
+        // Permutations of the axes with their parity
+        const PERMUTATIONS: [([usize; 3], i64); 6] = [
+            ([0, 1, 2], 1),
+            ([1, 2, 0], 1),
+            ([2, 0, 1], 1),
+            ([0, 2, 1], -1),
+            ([2, 1, 0], -1),
+            ([1, 0, 2], -1),
+        ];
+        const fn sign(negative: bool) -> i64 {
+            if negative {
+                -1
+            } else {
+                1
+            }
+        }
+
+        let mut all = [Self::IDENTITY; 24];
+        let mut count = 0;
+        let mut permutation = 0;
+        while permutation < PERMUTATIONS.len() {
+            let (axes, parity) = PERMUTATIONS[permutation];
+            let mut negated = 0;
+            while negated < 8 {
+                let signs = [
+                    sign(negated & 1 != 0),
+                    sign(negated & 2 != 0),
+                    sign(negated & 4 != 0),
+                ];
+                // Odd permutations need an odd number of flips to stay a rotation, not a mirror
+                if signs[0] * signs[1] * signs[2] == parity {
+                    all[count] = Rotation { axes, signs };
+                    count += 1;
+                }
+                negated += 1;
+            }
+            permutation += 1;
+        }
+        all
+    }
+
+    pub fn apply(&self, vector: Vector) -> Vector {
+        [0, 1, 2].map(|i| self.signs[i] * vector[self.axes[i]])
+    }
+
+    /// Rotation applying `self` then `next`.
+    pub fn then(&self, next: Rotation) -> Rotation {
+        Rotation {
+            axes: next.axes.map(|axis| self.axes[axis]),
+            signs: [0, 1, 2].map(|i| next.signs[i] * self.signs[next.axes[i]]),
+        }
+    }
+
+    pub fn inverse(&self) -> Rotation {
+        let mut inverse = Self::IDENTITY;
+        for i in 0..3 {
+            inverse.axes[self.axes[i]] = i;
+            inverse.signs[self.axes[i]] = self.signs[i];
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::Rotation;
+
+    #[test]
+    fn test_group() {
+        let all = Rotation::ALL.iter().copied().collect::<HashSet<_>>();
+        assert_eq!(all.len(), 24);
+        assert_eq!(Rotation::ALL[0], Rotation::IDENTITY);
+
+        let vector = [1, 2, 3];
+        for a in Rotation::ALL {
+            assert_eq!(a.then(a.inverse()), Rotation::IDENTITY);
+            for b in Rotation::ALL {
+                let both = a.then(b);
+                assert!(all.contains(&both));
+                assert_eq!(both.apply(vector), b.apply(a.apply(vector)));
+            }
+        }
+
+        // A quarter turn around z, with x going to y
+        let quarter = Rotation::ALL
+            .into_iter()
+            .find(|r| r.apply([1, 0, 0]) == [0, 1, 0] && r.apply([0, 0, 1]) == [0, 0, 1])
+            .unwrap();
+        assert_eq!(quarter.apply([0, 1, 0]), [-1, 0, 0]);
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+    hash::Hash,
+    ops::Add,
+};
+
+/// Cheapest way found from the start to a goal.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Path<N, C> {
+    pub cost: C,
+    /// Every node visited, from the start to the goal included.
+    pub nodes: Vec<N>,
+}
+
+/// The search ran out of nodes before reaching a goal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Unreachable;
+
+impl fmt::Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "No path leads to the goal")
+    }
+}
+
+impl Error for Unreachable {}
+
+/// Follows the parent links from `index` back to the start.
+fn reconstruct<N: Clone>(nodes: &[(N, Option<usize>)], mut index: usize) -> Vec<N> {
+    let mut path = vec![nodes[index].0.clone()];
+    while let Some(parent) = nodes[index].1 {
+        path.push(nodes[parent].0.clone());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`, `heuristic` being a lower
+/// bound of the remaining cost from a node.
+///
+/// # Examples
+///
+/// ```
+/// use common::search;
+///
+/// // Reach 10 from 1 by adding 1 (costs 1) or doubling (costs 3)
+/// let path = search::astar(
+///     1u32,
+///     |&n| [(n + 1, 1), (n * 2, 3)],
+///     |&n| u32::from(n < 10),
+///     |&n| n == 10,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(path.cost, 7);
+/// assert_eq!(path.nodes, [1, 2, 3, 4, 5, 10]);
+/// ```
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Result<Path<N, C>, Unreachable>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = vec![(start.clone(), None)];
+    let mut best = HashMap::from([(start.clone(), (C::default(), 0))]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = nodes[index].0.clone();
+        if best[&node].0 < cost {
+            // Superseded by a cheaper way to this node
+            continue;
+        }
+        if is_goal(&node) {
+            return Ok(Path {
+                cost,
+                nodes: reconstruct(&nodes, index),
+            });
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let next_index = match best.entry(next) {
+                Entry::Occupied(entry) if entry.get().0 <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    let next_index = entry.get().1;
+                    entry.insert((next_cost, next_index));
+                    nodes[next_index].1 = Some(index);
+                    next_index
+                }
+                Entry::Vacant(entry) => {
+                    nodes.push((entry.key().clone(), Some(index)));
+                    entry.insert((next_cost, nodes.len() - 1));
+                    nodes.len() - 1
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_index].0);
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    Err(Unreachable)
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Result<Path<N, C>, Unreachable>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Finds the path with the fewest steps from `start` to a node satisfying `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Result<Path<N, usize>, Unreachable>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = vec![(start.clone(), None)];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index].0) {
+            let nodes = reconstruct(&nodes, index);
+            return Ok(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+
+        for next in neighbours(&nodes[index].0) {
+            if seen.insert(next.clone()) {
+                nodes.push((next, Some(index)));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+
+    Err(Unreachable)
+}
+
+/// Every node reachable from `start`, itself included.
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut stack = vec![start.clone()];
+    let mut seen = HashSet::from([start]);
+
+    while let Some(node) = stack.pop() {
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                stack.push(next);
+            }
+        }
+    }
+
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bfs, dijkstra, reachable, Unreachable};
+
+    /// Edges of a small weighted graph where the direct route is not the cheapest one.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 1)],
+            'c' => vec![('d', 2)],
+            'd' => vec![('b', 1)],
+            'b' => vec![('e', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', edges, |&n| n == 'e').unwrap();
+
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.nodes, ['a', 'c', 'd', 'b', 'e']);
+        assert_eq!(dijkstra('a', edges, |&n| n == 'z'), Err(Unreachable));
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbours = |n: &char| edges(n).into_iter().map(|(next, _)| next);
+        let path = bfs('a', neighbours, |&n| n == 'e').unwrap();
+
+        assert_eq!(path.cost, 2);
+        assert_eq!(path.nodes, ['a', 'b', 'e']);
+        assert_eq!(bfs('e', neighbours, |&n| n == 'a'), Err(Unreachable));
+        assert_eq!(reachable('c', neighbours).len(), 4);
+    }
+}
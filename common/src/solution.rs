@@ -0,0 +1,181 @@
+use std::{fmt, marker::PhantomData, str::FromStr};
+
+use crate::Answer;
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::Part1 => write!(f, "part1"),
+            Part::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "part1" => Ok(Part::Part1),
+            "2" | "part2" => Ok(Part::Part2),
+            _ => anyhow::bail!("Unknown part {:?}, expected part1 or part2", s),
+        }
+    }
+}
+
+/// A day's puzzle, split so the input is parsed once and the model shared by both parts.
+///
+/// # Examples
+///
+/// ```
+/// use common::Solution;
+///
+/// struct Depths;
+///
+/// impl Solution for Depths {
+///     type Parsed<'i> = Vec<u32>;
+///     type Answer1 = usize;
+///     type Answer2 = u32;
+///
+///     fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+///         Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+///     }
+///
+///     fn part1(depths: &Vec<u32>) -> anyhow::Result<usize> {
+///         Ok(depths.len())
+///     }
+///
+///     fn part2(depths: &Vec<u32>) -> anyhow::Result<u32> {
+///         Ok(depths.iter().sum())
+///     }
+/// }
+///
+/// let depths = Depths::parse("199\n200").unwrap();
+/// assert_eq!(Depths::part2(&depths).unwrap(), 399);
+/// ```
+pub trait Solution: 'static {
+    /// Model of the puzzle input, it may borrow from the input.
+    type Parsed<'i>;
+    type Answer1: Into<Answer>;
+    type Answer2: Into<Answer>;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> anyhow::Result<Self::Answer1>;
+    fn part2(parsed: &Self::Parsed<'_>) -> anyhow::Result<Self::Answer2>;
+}
+
+/// Object safe view of a [`Solution`], as stored in the registry.
+pub trait Puzzle: Sync {
+    fn parse<'i>(&self, input: &'i str) -> anyhow::Result<Box<dyn Parsed + 'i>>;
+}
+
+/// Parsed input of a [`Puzzle`], ready to be solved any number of times.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> anyhow::Result<Answer>;
+}
+
+/// Adapter exposing a [`Solution`] as a [`Puzzle`].
+pub struct SolutionPuzzle<S>(PhantomData<fn() -> S>);
+
+impl<S> SolutionPuzzle<S> {
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S> Default for SolutionPuzzle<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct ParsedBy<'i, S: Solution>(S::Parsed<'i>);
+
+impl<'i, S: Solution> Parsed for ParsedBy<'i, S> {
+    fn solve(&self, part: Part) -> anyhow::Result<Answer> {
+        Ok(match part {
+            Part::Part1 => S::part1(&self.0)?.into(),
+            Part::Part2 => S::part2(&self.0)?.into(),
+        })
+    }
+}
+
+impl<S: Solution> Puzzle for SolutionPuzzle<S> {
+    fn parse<'i>(&self, input: &'i str) -> anyhow::Result<Box<dyn Parsed + 'i>> {
+        Ok(Box::new(ParsedBy::<S>(S::parse(input)?)))
+    }
+}
+
+/// Entry of the runner's registry, every day crate exposes one as `DAY` through
+/// [`register_day`](crate::register_day).
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    /// Directory of the day's crate, where its `input.txt` and `answers.toml` live.
+    pub dir: &'static str,
+    pub puzzle: &'static dyn Puzzle,
+}
+
+impl Day {
+    /// Parses an input, ignoring the line break files usually end with.
+    pub fn parse<'i>(&self, input: &'i str) -> anyhow::Result<Box<dyn Parsed + 'i>> {
+        self.puzzle.parse(input.trim_end_matches(['\n', '\r']))
+    }
+
+    pub fn run(&self, part: Part, input: &str) -> anyhow::Result<Answer> {
+        self.parse(input)?.solve(part)
+    }
+}
+
+/// Declares the `DAY` registry entry of a day crate from its [`Solution`].
+///
+/// The input is only read by the runner, so a crate builds without its `input.txt`.
+///
+/// # Examples
+///
+/// ```
+/// struct Lines;
+///
+/// impl common::Solution for Lines {
+///     type Parsed<'i> = Vec<&'i str>;
+///     type Answer1 = usize;
+///     type Answer2 = String;
+///
+///     fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+///         Ok(input.lines().collect())
+///     }
+///
+///     fn part1(lines: &Vec<&str>) -> anyhow::Result<usize> {
+///         Ok(lines.len())
+///     }
+///
+///     fn part2(lines: &Vec<&str>) -> anyhow::Result<String> {
+///         Ok(lines.concat())
+///     }
+/// }
+///
+/// common::register_day!(1, Lines);
+///
+/// assert_eq!(DAY.run(common::Part::Part2, "199\n200").unwrap().to_string(), "199200");
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    ($number:literal, $solution:ty) => {
+        pub const DAY: $crate::Day = $crate::Day {
+            number: $number,
+            dir: env!("CARGO_MANIFEST_DIR"),
+            puzzle: &$crate::SolutionPuzzle::<$solution>::new(),
+        };
+    };
+}
@@ -0,0 +1,96 @@
+//! Diagnostics emitted by the solutions, kept out of their output.
+//!
+//! Solutions report what they are doing through the [`log`] macros re-exported here, their target
+//! being the name of the day's crate by default. Nothing is shown until a program [`init`]s the
+//! facility, so library users and benchmarks only get the answers.
+//!
+//! ```
+//! common::trace::debug!("winning board {:?}", [[1, 2], [3, 4]]);
+//! ```
+
+use std::io::Write;
+
+pub use log::{debug, error, info, trace, warn, Level, LevelFilter};
+
+/// Which events are shown.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Filter {
+    /// Most verbose level shown.
+    pub level: LevelFilter,
+    /// Targets shown along with their submodules, every target when empty.
+    pub targets: Vec<String>,
+}
+
+impl Filter {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.level
+            && (self.targets.is_empty()
+                || self.targets.iter().any(|target| {
+                    metadata
+                        .target()
+                        .strip_prefix(target.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+                }))
+    }
+}
+
+/// Writes the events passing its filter to stderr.
+struct Logger(Filter);
+
+impl log::Log for Logger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        self.0.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            let _ = writeln!(
+                std::io::stderr().lock(),
+                "[{:<5} {}] {}",
+                record.level(),
+                record.target(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+    }
+}
+
+/// Shows the events passing `filter` on stderr, for the rest of the program.
+///
+/// Fails when a logger is already installed.
+pub fn init(filter: Filter) -> anyhow::Result<()> {
+    log::set_max_level(filter.level);
+    log::set_boxed_logger(Box::new(Logger(filter)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, Level, LevelFilter};
+
+    #[test]
+    fn test_filter() {
+        let enabled = |filter: &Filter, level, target| {
+            filter.enabled(&log::Metadata::builder().level(level).target(target).build())
+        };
+        let all = Filter {
+            level: LevelFilter::Debug,
+            targets: Vec::new(),
+        };
+        let day3 = Filter {
+            targets: vec!["day3".into()],
+            ..all.clone()
+        };
+
+        assert!(enabled(&all, Level::Debug, "day4"));
+        assert!(!enabled(&all, Level::Trace, "day4"));
+        assert!(enabled(&day3, Level::Warn, "day3"));
+        assert!(enabled(&day3, Level::Debug, "day3::parse"));
+        assert!(!enabled(&day3, Level::Debug, "day4"));
+        assert!(!enabled(&day3, Level::Debug, "day30"));
+    }
+}
@@ -1,72 +1,40 @@
+use common::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    let depths: Vec<u32> = input.lines().map(str::parse).try_collect()?;
-    Ok(depths
-        .into_iter()
-        .tuple_windows()
-        .filter(|(a, b)| a < b)
-        .count())
-}
+pub struct Day1;
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    let depths: Vec<u32> = input.lines().map(str::parse).try_collect()?;
-    Ok(depths
-        .into_iter()
-        .tuple_windows()
-        .map(|(a, b, c)| a + b + c)
-        .tuple_windows()
-        .filter(|(a, b)| a < b)
-        .count())
-}
+impl Solution for Day1 {
+    type Parsed<'i> = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"199
-200
-208
-210
-200
-207
-240
-269
-260
-263"#,
-                7,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+        Ok(input.lines().map(str::parse).try_collect()?)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"199
-200
-208
-210
-200
-207
-240
-269
-260
-263"#,
-                5,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(depths: &Vec<u32>) -> anyhow::Result<usize> {
+        Ok(depths.iter().tuple_windows().filter(|(a, b)| a < b).count())
     }
+
+    fn part2(depths: &Vec<u32>) -> anyhow::Result<usize> {
+        Ok(depths
+            .iter()
+            .tuple_windows()
+            .map(|(a, b, c)| a + b + c)
+            .tuple_windows()
+            .filter(|(a, b)| a < b)
+            .count())
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day1::part1(&Day1::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day1::part2(&Day1::parse(input)?)?.into())
 }
+
+common::register_day!(1, Day1);
+
+common::examples!();
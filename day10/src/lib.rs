@@ -1,4 +1,5 @@
 use anyhow::Context;
+use common::{Answer, Solution};
 use itertools::Itertools;
 
 fn matching(left: char, right: char) -> bool {
@@ -32,82 +33,68 @@ fn score2(left: char) -> u64 {
     }
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<u64> {
-    Ok(input
-        .lines()
-        .map(|line| {
-            let mut stack = Vec::with_capacity(line.len());
-            for c in line.chars() {
-                if is_opening(c) {
-                    stack.push(c)
-                } else if !matching(stack.pop().unwrap_or_default(), c) {
-                    return score1(c);
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'i> = Vec<&'i str>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Vec<&str>) -> anyhow::Result<u64> {
+        Ok(lines
+            .iter()
+            .map(|line| {
+                let mut stack = Vec::with_capacity(line.len());
+                for c in line.chars() {
+                    if is_opening(c) {
+                        stack.push(c)
+                    } else if !matching(stack.pop().unwrap_or_default(), c) {
+                        return score1(c);
+                    }
                 }
-            }
-            return 0;
-        })
-        .sum())
-}
+                0
+            })
+            .sum())
+    }
 
-pub fn challenge2(input: &str) -> anyhow::Result<u64> {
-    let mut scores = input
-        .lines()
-        .filter_map(|line| {
-            let mut stack = Vec::with_capacity(line.len());
-            for c in line.chars() {
-                if is_opening(c) {
-                    stack.push(c);
-                } else if !matching(stack.pop()?, c) {
-                    return None;
+    fn part2(lines: &Vec<&str>) -> anyhow::Result<u64> {
+        let mut scores = lines
+            .iter()
+            .filter_map(|line| {
+                let mut stack = Vec::with_capacity(line.len());
+                for c in line.chars() {
+                    if is_opening(c) {
+                        stack.push(c);
+                    } else if !matching(stack.pop()?, c) {
+                        return None;
+                    }
                 }
-            }
 
-            stack
-                .into_iter()
-                .rev()
-                .map(score2)
-                .reduce(|acc, v| acc * 5 + v)
-        })
-        .collect_vec();
+                stack
+                    .into_iter()
+                    .rev()
+                    .map(score2)
+                    .reduce(|acc, v| acc * 5 + v)
+            })
+            .collect_vec();
 
-    scores.sort();
-    scores.get(scores.len() / 2).copied().context("Not scores")
+        scores.sort();
+        scores.get(scores.len() / 2).copied().context("Not scores")
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"[({(<(())[]>[[{[]{<()<>>
-[(()[<>])]({[<{<<[]>>(
-{([(<{}[<>[]}>{[]{[(<()>
-(((({<>}<{<{<>}{[]{[]{}
-[[<[([]))<([[{}[[()]]]
-[{[{({}]{}}([{[{{{}}([]
-{<[[]]>}<{[{[{[]{()[[[]
-[<(<(<(<{}))><([]([]()
-<{([([[(<>()){}]>(<<{{
-<{([{{}}[<[[[<>{}]]]>[]]"#;
-
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 26397),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day10::part1(&Day10::parse(input)?)?.into())
+}
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 288957),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day10::part2(&Day10::parse(input)?)?.into())
 }
+
+common::register_day!(10, Day10);
+
+common::examples!();
@@ -1,147 +1,73 @@
 use std::iter;
 
 use anyhow::Context;
+use common::{Answer, Grid, Solution};
 use itertools::Itertools;
-use nom::{
-    character::complete::{anychar, newline},
-    combinator::map_opt,
-    multi::{many1, separated_list1},
-    Parser,
-};
-use nom_supreme::error::ErrorTree;
-
-fn map<'i>() -> impl Parser<&'i str, Vec<Vec<u32>>, ErrorTree<&'i str>> {
-    separated_list1(newline, many1(map_opt(anychar, |c| c.to_digit(10))))
-}
-
-fn get_mut(
-    map: &mut Vec<Vec<u32>>,
-    (i, j): (usize, usize),
-    direction: Direction,
-) -> Option<&mut u32> {
-    let (i, j) = match direction {
-        Direction::North => (i.checked_sub(1)?, j),
-        Direction::NorthEast => (i.checked_sub(1)?, j + 1),
-        Direction::East => (i, j + 1),
-        Direction::SouthEast => (i + 1, j + 1),
-        Direction::South => (i + 1, j),
-        Direction::SouthWest => (i + 1, j.checked_sub(1)?),
-        Direction::West => (i, j.checked_sub(1)?),
-        Direction::NorthWest => (i.checked_sub(1)?, j.checked_sub(1)?),
-    };
-
-    map.get_mut(i)?.get_mut(j)
-}
-
-// 🤩 #![feature(mixed_integer_ops)] would be better than this
-enum Direction {
-    North,
-    NorthEast,
-    East,
-    SouthEast,
-    South,
-    SouthWest,
-    West,
-    NorthWest,
-}
 
-fn simulate(mut map: Vec<Vec<u32>>) -> impl Iterator<Item = usize> {
-    use Direction::*;
+fn simulate(mut map: Grid<u32>) -> impl Iterator<Item = usize> {
     iter::repeat_with(move || {
-        let mut flashed = map.iter().map(|row| vec![false; row.len()]).collect_vec();
-        map.iter_mut()
-            .flat_map(|r| r.iter_mut())
-            .for_each(|e| *e += 1);
+        let mut flashed = map.map(|_| false);
+        map.values_mut().for_each(|e| *e += 1);
 
         loop {
-            let flashers =
-                map.iter()
-                    .zip(flashed.iter())
-                    .enumerate()
-                    .flat_map(|(i, (row, flashed))| {
-                        row.iter().zip(flashed.iter()).enumerate().filter_map(
-                            move |(j, (v, flashed))| (!*flashed && *v > 9).then(|| (i, j)),
-                        )
-                    })
-                    .collect_vec();
+            let flashers = map
+                .iter()
+                .filter(|&(position, v)| !flashed[position] && *v > 9)
+                .map(|(position, _)| position)
+                .collect_vec();
 
             if flashers.is_empty() {
                 break;
             }
 
             for position in flashers {
-                flashed[position.0][position.1] = true;
-                for direction in [
-                    North, NorthEast, East, SouthEast, South, SouthWest, West, NorthWest,
-                ] {
-                    if let Some(v) = get_mut(&mut map, position, direction) {
-                        *v += 1;
-                    }
+                flashed[position] = true;
+                for neighbour in map.neighbours8(position).collect_vec() {
+                    map[neighbour] += 1;
                 }
             }
         }
 
-        map.iter_mut()
-            .zip(flashed.iter())
-            .flat_map(|(row, flashed)| row.iter_mut().zip(flashed.iter()))
-            .filter(|(_, flashed)| **flashed)
-            .for_each(|(v, _)| *v = 0);
-
-        flashed
-            .into_iter()
-            .flat_map(Vec::into_iter)
-            .filter(|f| *f)
-            .count()
+        for (position, _) in flashed.iter().filter(|(_, flashed)| **flashed) {
+            map[position] = 0;
+        }
+
+        flashed.iter().filter(|(_, flashed)| **flashed).count()
     })
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    let map = common::parse(input, map())?;
-    Ok(simulate(map).take(100).sum())
-}
+pub struct Day11;
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    let map = common::parse(input, map())?;
-    let goal = map.iter().flat_map(|i| i.iter()).count();
-    simulate(map)
-        .position(|c| c == goal)
-        .map(|p| p + 1)
-        .context("No steps to run")
-}
+impl Solution for Day11 {
+    type Parsed<'i> = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"5483143223
-2745854711
-5264556173
-6141336146
-6357385478
-4167524645
-2176841721
-6882881134
-4846848554
-5283751526"#;
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 1656),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+        Ok(common::parse(input, Grid::digits())?)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 195),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(map: &Grid<u32>) -> anyhow::Result<usize> {
+        Ok(simulate(map.clone()).take(100).sum())
+    }
+
+    fn part2(map: &Grid<u32>) -> anyhow::Result<usize> {
+        let goal = map.width() * map.height();
+        simulate(map.clone())
+            .position(|c| c == goal)
+            .map(|p| p + 1)
+            .context("No steps to run")
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day11::part1(&Day11::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day11::part2(&Day11::parse(input)?)?.into())
+}
+
+common::register_day!(11, Day11);
+
+common::examples!();
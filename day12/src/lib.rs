@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use common::{Answer, Solution};
 use nom::{
     branch::alt,
     character::complete::{alpha1, char, newline},
@@ -20,14 +21,17 @@ impl<'i> Cave<'i> {
         alt((
             alpha1
                 .verify(|s: &&str| s.chars().all(|c| c.is_lowercase()))
-                .map(|s| Cave::Small(s)),
+                .map(Cave::Small),
             alpha1
                 .verify(|s: &&str| s.chars().all(|c| c.is_uppercase()))
-                .map(|s| Cave::Large(s)),
+                .map(Cave::Large),
         ))
     }
 }
 
+/// Links between caves, in both directions.
+pub struct Caves<'i>(HashMap<Cave<'i>, Vec<Cave<'i>>>);
+
 fn walk<'c, F: FnMut(&HashMap<Cave<'c>, usize>, &Cave) -> bool>(
     links: &HashMap<Cave<'c>, Vec<Cave<'c>>>,
     stack: &mut HashMap<Cave<'c>, usize>,
@@ -50,128 +54,82 @@ fn walk<'c, F: FnMut(&HashMap<Cave<'c>, usize>, &Cave) -> bool>(
     count
 }
 
-fn challenge<F>(input: &str, mut go: F) -> anyhow::Result<usize>
+fn challenge<F>(Caves(link_map): &Caves, mut go: F) -> anyhow::Result<usize>
 where
     F: FnMut(&HashMap<Cave, usize>, &Cave) -> bool,
 {
-    let links = common::parse(
-        input,
-        separated_list1(
-            newline,
-            separated_pair(Cave::parser(), char('-'), Cave::parser()),
-        ),
-    )?;
-
-    let link_map: HashMap<Cave, Vec<Cave>> =
-        links.iter().fold(Default::default(), |mut acc, (a, b)| {
-            acc.entry(*a).or_default().push(*b);
-            acc.entry(*b).or_default().push(*a);
-            acc
-        });
-
     let mut stack = [(Cave::Small("start"), 1)].into_iter().collect();
-    Ok(walk(&link_map, &mut stack, Cave::Small("start"), &mut go))
+    Ok(walk(link_map, &mut stack, Cave::Small("start"), &mut go))
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    challenge(input, |previous, next| {
-        matches!(next, Cave::Large(..)) || previous.get(next).copied().unwrap_or_default() == 0
-    })
-}
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'i> = Caves<'i>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Caves<'_>> {
+        let links = common::parse(
+            input,
+            separated_list1(
+                newline,
+                separated_pair(Cave::parser(), char('-'), Cave::parser()),
+            ),
+        )?;
+
+        Ok(Caves(links.iter().fold(
+            Default::default(),
+            |mut acc, (a, b)| {
+                acc.entry(*a).or_default().push(*b);
+                acc.entry(*b).or_default().push(*a);
+                acc
+            },
+        )))
+    }
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    challenge(input, |previous, next| {
-        if matches!(next, Cave::Large(..)) {
-            return true;
-        }
-        if *next == Cave::Small("start") {
-            return false;
-        }
+    fn part1(caves: &Caves) -> anyhow::Result<usize> {
+        challenge(caves, |previous, next| {
+            matches!(next, Cave::Large(..)) || previous.get(next).copied().unwrap_or_default() == 0
+        })
+    }
 
-        previous
-            .get(next)
-            .map(|count| {
-                if *count == 0 {
-                    true
-                } else if *count == 1 {
-                    previous.iter().all(|(cave, n)| match cave {
-                        Cave::Small(..) => *n <= 1,
-                        Cave::Large(..) => true,
-                    })
-                } else {
-                    false
-                }
-            })
-            .unwrap_or(true)
-    })
-}
+    fn part2(caves: &Caves) -> anyhow::Result<usize> {
+        challenge(caves, |previous, next| {
+            if matches!(next, Cave::Large(..)) {
+                return true;
+            }
+            if *next == Cave::Small("start") {
+                return false;
+            }
 
-#[cfg(test)]
-mod tests {
-
-    const EXAMPLE1: &str = r#"start-A
-start-b
-A-c
-A-b
-b-d
-A-end
-b-end"#;
-
-    const EXAMPLE2: &str = r#"dc-end
-HN-start
-start-kj
-dc-start
-dc-HN
-LN-dc
-HN-end
-kj-sa
-kj-HN
-kj-dc"#;
-
-    const EXAMPLE3: &str = r#"fs-end
-he-DX
-fs-he
-start-DX
-pj-DX
-end-zg
-zg-sl
-zg-pj
-pj-he
-RW-he
-fs-DX
-pj-RW
-zg-RW
-start-pj
-he-WI
-zg-he
-pj-fs
-start-RW"#;
-
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE1, 10),
-            (EXAMPLE2, 19),
-            (EXAMPLE3, 226),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+            previous
+                .get(next)
+                .map(|count| {
+                    if *count == 0 {
+                        true
+                    } else if *count == 1 {
+                        previous.iter().all(|(cave, n)| match cave {
+                            Cave::Small(..) => *n <= 1,
+                            Cave::Large(..) => true,
+                        })
+                    } else {
+                        false
+                    }
+                })
+                .unwrap_or(true)
+        })
     }
+}
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE1, 36),
-            (EXAMPLE2, 103),
-            (EXAMPLE3, 3509),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day12::part1(&Day12::parse(input)?)?.into())
 }
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day12::part2(&Day12::parse(input)?)?.into())
+}
+
+common::register_day!(12, Day12);
+
+common::examples!();
@@ -1,6 +1,7 @@
 use std::collections::HashSet;
 
 use anyhow::Context;
+use common::{ocr, Answer, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -32,6 +33,12 @@ fn folds<'i>() -> impl Parser<&'i str, Vec<(bool, usize)>, ErrorTree<&'i str>> {
     )
 }
 
+/// Transparent sheet dots and the folding instructions.
+pub struct Manual {
+    pub points: Vec<(usize, usize)>,
+    pub folds: Vec<(bool, usize)>,
+}
+
 fn fold(map: &mut HashSet<(usize, usize)>, (vertical, position): (bool, usize)) {
     // drain_filter is going to be so nice
     let (original, mirror) = map
@@ -49,117 +56,48 @@ fn fold(map: &mut HashSet<(usize, usize)>, (vertical, position): (bool, usize))
     }));
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    let (points, folds) = common::parse(
-        input,
-        separated_pair(points().terminated(newline), newline, folds()),
-    )?;
+pub struct Day13;
 
-    let mut map: HashSet<(usize, usize)> = points.into_iter().collect();
-    fold(&mut map, *folds.get(0).context("No folds")?);
+impl Solution for Day13 {
+    type Parsed<'i> = Manual;
+    type Answer1 = usize;
+    type Answer2 = String;
 
-    Ok(map.len())
-}
+    fn parse(input: &str) -> anyhow::Result<Manual> {
+        let (points, folds) = common::parse(
+            input,
+            separated_pair(points().terminated(newline), newline, folds()),
+        )?;
 
-// Answer is between 983 and 766
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    let (points, folds) = common::parse(
-        input,
-        separated_pair(points().terminated(newline), newline, folds()),
-    )?;
+        Ok(Manual { points, folds })
+    }
 
-    let mut map: HashSet<(usize, usize)> = points.into_iter().collect();
+    fn part1(Manual { points, folds }: &Manual) -> anyhow::Result<usize> {
+        let mut map: HashSet<(usize, usize)> = points.iter().copied().collect();
+        fold(&mut map, *folds.first().context("No folds")?);
 
-    for instruction in folds {
-        fold(&mut map, instruction);
+        Ok(map.len())
     }
 
-    let (width, height) = map
-        .iter()
-        .copied()
-        .reduce(|acc, (x, y)| (acc.0.max(x), acc.1.max(y)))
-        .map(|(x, y)| (x + 1, y + 1))
-        .context("not points")?;
-
-    for j in 0..height {
-        for i in 0..width {
-            if map.contains(&(i, j)) {
-                print!("█");
-            } else {
-                print!(" ");
-            }
+    fn part2(Manual { points, folds }: &Manual) -> anyhow::Result<String> {
+        let mut map: HashSet<(usize, usize)> = points.iter().copied().collect();
+
+        for &instruction in folds {
+            fold(&mut map, instruction);
         }
-        println!("");
-    }
 
-    Ok(map.len())
+        ocr::read_points(map.into_iter().map(|(x, y)| (y, x)))
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"6,10
-0,14
-9,10
-0,3
-10,4
-4,11
-6,0
-6,12
-4,1
-0,13
-10,12
-3,4
-3,0
-8,4
-1,10
-2,14
-8,10
-9,0
-
-fold along y=7
-fold along x=5"#;
-
-    const EXAMPLE2: &str = r#"10,10
-6,14
-4,13
-10,12
-9,14
-6,10
-6,12
-2,14
-3,10
-9,10
-0,14
-0,11
-3,14
-8,10
-1,10
-0,13
-4,11
-
-fold along x=5"#;
-
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 17),
-            (EXAMPLE2, 16),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day13::part1(&Day13::parse(input)?)?.into())
+}
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day13::part2(&Day13::parse(input)?)?.into())
 }
+
+common::register_day!(13, Day13);
+
+common::examples!();
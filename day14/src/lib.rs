@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
+use common::{Answer, Solution};
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
@@ -11,11 +12,13 @@ use nom::{
 };
 use nom_supreme::{error::ErrorTree, ParserExt};
 
-fn insertion<'i>() -> impl Parser<&'i str, ((char, char), char), ErrorTree<&'i str>> {
+type Pair = (char, char);
+
+fn insertion<'i>() -> impl Parser<&'i str, (Pair, char), ErrorTree<&'i str>> {
     separated_pair(tuple((anychar, anychar)), tag(" -> "), anychar)
 }
 
-fn puzzle<'i>() -> impl Parser<&'i str, (&'i str, Vec<((char, char), char)>), ErrorTree<&'i str>> {
+fn puzzle<'i>() -> impl Parser<&'i str, (&'i str, Vec<(Pair, char)>), ErrorTree<&'i str>> {
     separated_pair(
         alpha1,
         newline.terminated(newline),
@@ -30,10 +33,10 @@ fn merge(acc: &mut HashMap<char, usize>, source: impl Iterator<Item = (char, usi
 }
 
 fn count(
-    pair: (char, char),
+    pair: Pair,
     depth: usize,
-    map: &HashMap<(char, char), char>,
-    cache: &mut HashMap<((char, char), usize), HashMap<char, usize>>,
+    map: &HashMap<Pair, char>,
+    cache: &mut HashMap<(Pair, usize), HashMap<char, usize>>,
 ) -> impl Iterator<Item = (char, usize)> {
     let counts = if let Some(cached) = cache.get(&(pair, depth)) {
         cached.clone()
@@ -55,14 +58,17 @@ fn count(
     counts.into_iter()
 }
 
-pub fn challenge(input: &str, rounds: usize) -> anyhow::Result<usize> {
-    let (template, inserts) = common::parse(input, puzzle())?;
-    let inserts: HashMap<(char, char), char> = inserts.iter().copied().collect();
+/// Polymer template and its pair insertion rules.
+pub struct Polymer<'i> {
+    pub template: &'i str,
+    pub inserts: HashMap<Pair, char>,
+}
 
+pub fn challenge(Polymer { template, inserts }: &Polymer, rounds: usize) -> anyhow::Result<usize> {
     let mut result = template.chars().counts();
     let mut cache = Default::default();
     for pair in template.chars().tuple_windows() {
-        merge(&mut result, count(pair, rounds - 1, &inserts, &mut cache));
+        merge(&mut result, count(pair, rounds - 1, inserts, &mut cache));
     }
 
     result
@@ -73,55 +79,39 @@ pub fn challenge(input: &str, rounds: usize) -> anyhow::Result<usize> {
         .context("No counts")
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    challenge(input, 10)
-}
+pub struct Day14;
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    challenge(input, 40)
-}
+impl Solution for Day14 {
+    type Parsed<'i> = Polymer<'i>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"NNCB
-
-CH -> B
-HH -> N
-CB -> H
-NH -> C
-HB -> C
-HC -> B
-HN -> C
-NN -> C
-BH -> H
-NC -> B
-NB -> B
-BN -> B
-BB -> N
-BC -> B
-CC -> N
-CN -> C"#;
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 1588),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn parse(input: &str) -> anyhow::Result<Polymer<'_>> {
+        let (template, inserts) = common::parse(input, puzzle())?;
+
+        Ok(Polymer {
+            template,
+            inserts: inserts.into_iter().collect(),
+        })
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 2188189693529),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(polymer: &Polymer) -> anyhow::Result<usize> {
+        challenge(polymer, 10)
     }
+
+    fn part2(polymer: &Polymer) -> anyhow::Result<usize> {
+        challenge(polymer, 40)
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day14::part1(&Day14::parse(input)?)?.into())
 }
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day14::part2(&Day14::parse(input)?)?.into())
+}
+
+common::register_day!(14, Day14);
+
+common::examples!();
@@ -1,120 +1,47 @@
-use std::{
-    cmp::Reverse,
-    collections::{BinaryHeap, HashSet},
-};
-
-use anyhow::Context;
-use nom::{
-    character::complete::{anychar, newline},
-    combinator::map_opt,
-    multi::{many1, separated_list1},
-    Parser,
-};
-use nom_supreme::error::ErrorTree;
-
-fn line<'i>() -> impl Parser<&'i str, Vec<u32>, ErrorTree<&'i str>> {
-    many1(map_opt(anychar, |c| c.to_digit(10)))
+use common::{search, Answer, Grid, Solution};
+
+/// Lowest total risk of a path from the top left to the bottom right of the map.
+pub fn lowest_risk(map: &Grid<u32>) -> anyhow::Result<u32> {
+    let goal = (map.height() - 1, map.width() - 1);
+    let path = search::dijkstra(
+        (0, 0),
+        |&position| map.neighbours4(position).map(|next| (next, map[next])),
+        |&position| position == goal,
+    )?;
+
+    Ok(path.cost)
 }
 
-pub fn challenge(
-    mut risk_at: impl FnMut((usize, usize)) -> Option<u32>,
-    goal: (usize, usize),
-) -> anyhow::Result<u32> {
-    let mut visited = HashSet::new();
-    let mut lowest_risk = BinaryHeap::new();
-    lowest_risk.extend([
-        Reverse((risk_at((0, 1)).context("Missing map")?, (0, 1))),
-        Reverse((risk_at((1, 0)).context("Missing map")?, (1, 0))),
-    ]);
+pub struct Day15;
 
-    while let Some(Reverse((risk, position @ (x, y)))) = lowest_risk.pop() {
-        if position == goal {
-            return Ok(risk);
-        }
+impl Solution for Day15 {
+    type Parsed<'i> = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-        lowest_risk.extend(
-            [
-                (x != 0).then(|| (x - 1, y)),
-                (y != 0).then(|| (x, y - 1)),
-                Some((x + 1, y)),
-                Some((x, y + 1)),
-            ]
-            .into_iter()
-            .flatten()
-            .filter(|position| visited.insert(*position))
-            .filter_map(|position| Some((risk + risk_at(position)?, position)))
-            .map(Reverse),
-        );
+    fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+        Ok(common::parse(input, Grid::digits())?)
     }
 
-    unreachable!()
-}
+    fn part1(map: &Grid<u32>) -> anyhow::Result<u32> {
+        lowest_risk(map)
+    }
 
-pub fn challenge1(input: &str) -> anyhow::Result<u32> {
-    let map = common::parse(input, separated_list1(newline, line()))?;
-    let width = map.len();
-    let height = map.first().context("Empty")?.len();
+    fn part2(map: &Grid<u32>) -> anyhow::Result<u32> {
+        let map = map.tile(5, 5, |risk, (i, j)| (*risk + (i + j) as u32 - 1) % 9 + 1);
 
-    challenge(
-        move |(x, y)| map.get(x).and_then(|r| r.get(y)).copied(),
-        (width - 1, height - 1),
-    )
+        lowest_risk(&map)
+    }
 }
 
-pub fn challenge2(input: &str) -> anyhow::Result<u32> {
-    let map = common::parse(input, separated_list1(newline, line()))?;
-    let width = map.len();
-    let height = map.first().context("Empty")?.len();
-    let goal = (width * 5 - 1, height * 5 - 1);
-
-    challenge(
-        move |(x, y)| {
-            let row = map.get(x % map.len())?;
-            let risk = row.get(y % row.len())?;
-            let i = (x / map.len()) as u32;
-            let j = (y / row.len()) as u32;
-
-            (i < 5 && j < 5).then(|| ((*risk + i + j - 1) % 9) + 1)
-        },
-        goal,
-    )
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day15::part1(&Day15::parse(input)?)?.into())
 }
 
-#[cfg(test)]
-mod tests {
-
-    const EXAMPLE: &str = r#"1163751742
-1381373672
-2136511328
-3694931569
-7463417111
-1319128137
-1359912421
-3125421639
-1293138521
-2311944581"#;
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day15::part2(&Day15::parse(input)?)?.into())
+}
 
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 40),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+common::register_day!(15, Day15);
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 315),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
-}
+common::examples!();
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use common::{Answer, Solution};
 use itertools::Itertools;
 use nom::{
     bits::{
@@ -13,6 +14,8 @@ use nom::{
     IResult, Parser,
 };
 
+type Bits<'i> = (&'i [u8], usize);
+
 #[derive(Debug)]
 enum Packet {
     Literal {
@@ -35,7 +38,7 @@ enum Operation {
     EqualTo,
 }
 
-fn operation<'i>() -> impl Parser<(&'i [u8], usize), Operation, VerboseError<(&'i [u8], usize)>> {
+fn operation<'i>() -> impl Parser<Bits<'i>, Operation, VerboseError<Bits<'i>>> {
     alt((
         value(Operation::Sum, tag(0, 3usize)),
         value(Operation::Product, tag(1, 3usize)),
@@ -47,9 +50,7 @@ fn operation<'i>() -> impl Parser<(&'i [u8], usize), Operation, VerboseError<(&'
     ))
 }
 
-fn operator(
-    input: (&[u8], usize),
-) -> IResult<(&[u8], usize), Packet, VerboseError<(&[u8], usize)>> {
+fn operator(input: Bits) -> IResult<Bits, Packet, VerboseError<Bits>> {
     let (r, operation) = operation().parse(input)?;
     let (r, len_id): (_, u8) = take(1usize)(r)?;
     let mut sub_packets = Vec::new();
@@ -84,7 +85,7 @@ fn operator(
     ))
 }
 
-fn literal<'i>() -> impl Parser<(&'i [u8], usize), Packet, VerboseError<(&'i [u8], usize)>> {
+fn literal<'i>() -> impl Parser<Bits<'i>, Packet, VerboseError<Bits<'i>>> {
     map(
         preceded(
             tag(4, 3usize),
@@ -103,13 +104,7 @@ fn literal<'i>() -> impl Parser<(&'i [u8], usize), Packet, VerboseError<(&'i [u8
     )
 }
 
-fn packet<'i>() -> impl FnMut(
-    (&'i [u8], usize),
-) -> IResult<
-    (&'i [u8], usize),
-    (u8, Packet),
-    VerboseError<(&'i [u8], usize)>,
-> {
+fn packet<'i>() -> impl FnMut(Bits<'i>) -> IResult<Bits<'i>, (u8, Packet), VerboseError<Bits<'i>>> {
     tuple((take(3usize), alt((literal(), operator))))
 }
 
@@ -117,14 +112,14 @@ fn sum_of_versions((version, packet): &(u8, Packet)) -> u64 {
     match packet {
         Packet::Literal { .. } => u64::from(*version),
         Packet::Operator { sub_packets, .. } => {
-            sub_packets.into_iter().map(sum_of_versions).sum::<u64>() + u64::from(*version)
+            sub_packets.iter().map(sum_of_versions).sum::<u64>() + u64::from(*version)
         }
     }
 }
 
 fn eval((_, packet): &(u8, Packet)) -> u64 {
     match packet {
-        Packet::Literal { value } => return *value,
+        Packet::Literal { value } => *value,
         Packet::Operator {
             operation,
             sub_packets,
@@ -153,71 +148,56 @@ fn eval((_, packet): &(u8, Packet)) -> u64 {
     }
 }
 
-fn challenge(input: &str, tally: impl FnOnce(&(u8, Packet)) -> u64) -> anyhow::Result<u64> {
-    let bytes = input
-        .trim()
-        .chars()
-        .tuples()
-        .map(|(a, b)| {
-            a.to_digit(16)
-                .zip(b.to_digit(16))
-                .map(|(a, b)| (a << 4 | b) as u8)
-                .context("Invalid hex")
-        })
-        .collect::<anyhow::Result<Vec<u8>>>()?;
-
-    let message = bits(all_consuming(terminated(packet(), many0(tag(0, 1usize)))))
-        .parse(bytes.as_slice())
-        .map_err(|e: nom::Err<VerboseError<&[u8]>>| anyhow::format_err!("Unable to parse {}", e))?
-        .1;
-
-    Ok(tally(&message))
+/// Outermost packet of the transmission, with its version.
+pub struct Transmission((u8, Packet));
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'i> = Transmission;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Transmission> {
+        let bytes = input
+            .trim()
+            .chars()
+            .tuples()
+            .map(|(a, b)| {
+                a.to_digit(16)
+                    .zip(b.to_digit(16))
+                    .map(|(a, b)| (a << 4 | b) as u8)
+                    .context("Invalid hex")
+            })
+            .collect::<anyhow::Result<Vec<u8>>>()?;
+
+        let message = bits(all_consuming(terminated(packet(), many0(tag(0, 1usize)))))
+            .parse(bytes.as_slice())
+            .map_err(|e: nom::Err<VerboseError<&[u8]>>| {
+                anyhow::format_err!("Unable to parse {}", e)
+            })?
+            .1;
+
+        Ok(Transmission(message))
+    }
+
+    fn part1(Transmission(message): &Transmission) -> anyhow::Result<u64> {
+        Ok(sum_of_versions(message))
+    }
+
+    fn part2(Transmission(message): &Transmission) -> anyhow::Result<u64> {
+        Ok(eval(message))
+    }
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<u64> {
-    challenge(input, sum_of_versions)
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day16::part1(&Day16::parse(input)?)?.into())
 }
 
-pub fn challenge2(input: &str) -> anyhow::Result<u64> {
-    challenge(input, eval)
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day16::part2(&Day16::parse(input)?)?.into())
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("D2FE28", 6),
-            ("38006F45291200", 9),
-            ("EE00D40C823060", 14),
-            ("8A004A801A8002F478", 16),
-            ("620080001611562C8802118E34", 12),
-            ("C0015000016115A2E0802F182340", 23),
-            ("A0016C880162017C3686B18A3D4780", 31),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+common::register_day!(16, Day16);
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("C200B40A82", 3),
-            ("04005AC33890", 54),
-            ("880086C3E88112", 7),
-            ("CE00C43D881120", 9),
-            ("D8005AC2A8F0", 1),
-            ("F600BC2D8F", 0),
-            ("9C005AC2F8F0", 0),
-            ("9C0141080250320F1802104A08", 1),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
-}
+common::examples!();
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use common::{Answer, Solution};
 use itertools::Itertools;
 use nom::{
     character::complete::digit1,
@@ -16,17 +17,18 @@ fn range<'i>() -> impl Parser<&'i str, (i32, i32), ErrorTree<&'i str>> {
     separated_pair(number(), tag(".."), number())
 }
 
-fn puzzle<'i>() -> impl Parser<&'i str, ((i32, i32), (i32, i32)), ErrorTree<&'i str>> {
+/// Target area as its x and y ranges.
+type Area = ((i32, i32), (i32, i32));
+
+fn puzzle<'i>() -> impl Parser<&'i str, Area, ErrorTree<&'i str>> {
     preceded(
         tag("target area: x="),
         separated_pair(range(), tag(", y="), range()),
     )
 }
 
-fn challenge(input: &str) -> anyhow::Result<impl Iterator<Item = i32>> {
-    let ((xmin, xmax), (ymin, ymax)) = common::parse(input, puzzle())?;
-
-    Ok((1..=xmax)
+fn challenge(&((xmin, xmax), (ymin, ymax)): &Area) -> impl Iterator<Item = i32> {
+    (1..=xmax)
         .cartesian_product(ymin..=-ymin)
         .filter_map(move |velocity| {
             let mut max_height = 0;
@@ -45,40 +47,37 @@ fn challenge(input: &str) -> anyhow::Result<impl Iterator<Item = i32>> {
                 }
             }
             None
-        }))
+        })
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<i32> {
-    challenge(input)?.max().context("Nothing went in")
-}
+pub struct Day17;
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    Ok(challenge(input)?.count())
-}
+impl Solution for Day17 {
+    type Parsed<'i> = Area;
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Area> {
+        Ok(common::parse(input, puzzle())?)
+    }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("target area: x=20..30, y=-10..-5", 45),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(area: &Area) -> anyhow::Result<i32> {
+        challenge(area).max().context("Nothing went in")
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("target area: x=20..30, y=-10..-5", 112),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part2(area: &Area) -> anyhow::Result<usize> {
+        Ok(challenge(area).count())
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day17::part1(&Day17::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day17::part2(&Day17::parse(input)?)?.into())
+}
+
+common::register_day!(17, Day17);
+
+common::examples!();
@@ -1,6 +1,7 @@
 use std::ops::{Add, AddAssign, ControlFlow, Deref};
 
 use anyhow::Context;
+use common::{Answer, Solution};
 use nom::{
     branch::alt,
     character::complete::{char, digit1, newline},
@@ -44,7 +45,7 @@ impl Number {
                 char(']'),
             )
             .map(|(left, right)| Number::pair(left, right)),
-            digit1.parse_from_str().map(|l| Number::Literal(l)),
+            digit1.parse_from_str().map(Number::Literal),
         ))
         .parse(input)
     }
@@ -140,69 +141,59 @@ impl Number {
     }
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<u32> {
-    let mut lines = common::parse(input, separated_list1(newline, Number::parse))?.into_iter();
-    let mut number = lines.next().context("No lines")?;
+/// Snailfish numbers of the homework assignment.
+pub struct Homework(Vec<Number>);
 
-    for next in lines {
-        number += next;
-        number.reduce()?;
-    }
-
-    Ok(number.magnitude())
-}
-
-pub fn challenge2(input: &str) -> anyhow::Result<u32> {
-    let lines = common::parse(input, separated_list1(newline, Number::parse))?;
+pub struct Day18;
 
-    let combinations = (0..lines.len())
-        .flat_map(|a| (0..lines.len()).map(move |b| (a, b)))
-        .filter(|(a, b)| a != b);
+impl Solution for Day18 {
+    type Parsed<'i> = Homework;
+    type Answer1 = u32;
+    type Answer2 = u32;
 
-    let mut max = 0;
-    for (a, b) in combinations {
-        let mut number = lines[a].clone() + lines[b].clone();
-        number.reduce()?;
-        max = max.max(number.magnitude());
+    fn parse(input: &str) -> anyhow::Result<Homework> {
+        Ok(Homework(common::parse(
+            input,
+            separated_list1(newline, Number::parse),
+        )?))
     }
 
-    Ok(max)
-}
+    fn part1(Homework(lines): &Homework) -> anyhow::Result<u32> {
+        let mut lines = lines.iter().cloned();
+        let mut number = lines.next().context("No lines")?;
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
-[[[5,[2,8]],4],[5,[[9,9],0]]]
-[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
-[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
-[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
-[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
-[[[[5,4],[7,7]],8],[[8,3],8]]
-[[9,3],[[9,9],[6,[4,9]]]]
-[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
-[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"#;
-
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 4140),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
+        for next in lines {
+            number += next;
+            number.reduce()?;
         }
-        Ok(())
+
+        Ok(number.magnitude())
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 3993),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
+    fn part2(Homework(lines): &Homework) -> anyhow::Result<u32> {
+        let combinations = (0..lines.len())
+            .flat_map(|a| (0..lines.len()).map(move |b| (a, b)))
+            .filter(|(a, b)| a != b);
+
+        let mut max = 0;
+        for (a, b) in combinations {
+            let mut number = lines[a].clone() + lines[b].clone();
+            number.reduce()?;
+            max = max.max(number.magnitude());
         }
-        Ok(())
+
+        Ok(max)
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day18::part1(&Day18::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day18::part2(&Day18::parse(input)?)?.into())
+}
+
+common::register_day!(18, Day18);
+
+common::examples!();
@@ -0,0 +1,4 @@
+fn main() {
+    // Rebuild, and regenerate the example tests, when examples are added
+    println!("cargo:rerun-if-changed=examples");
+}
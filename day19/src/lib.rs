@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use anyhow::Context;
+use common::{
+    rotation::{Rotation, Vector},
+    Answer, Solution,
+};
+use itertools::Itertools;
+use nom::{
+    character::complete::{char, digit1, newline},
+    combinator::{opt, recognize},
+    multi::separated_list1,
+    sequence::{pair, preceded, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, parse_from_str, tag::complete::tag, ParserExt};
+
+fn number<'i>() -> impl Parser<&'i str, i64, ErrorTree<&'i str>> {
+    parse_from_str(recognize(tuple((opt(tag("-")), digit1))))
+}
+
+fn beacon<'i>() -> impl Parser<&'i str, Vector, ErrorTree<&'i str>> {
+    tuple((
+        number(),
+        preceded(char(','), number()),
+        preceded(char(','), number()),
+    ))
+    .map(|(x, y, z)| [x, y, z])
+}
+
+/// Beacons seen by a scanner, relative to it.
+type Scanner = Vec<Vector>;
+
+fn scanner<'i>() -> impl Parser<&'i str, Scanner, ErrorTree<&'i str>> {
+    preceded(
+        terminated(tuple((tag("--- scanner "), digit1, tag(" ---"))), newline),
+        separated_list1(newline, beacon()),
+    )
+    .context("scanner")
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: Vector, b: Vector) -> Vector {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn manhattan(a: Vector, b: Vector) -> i64 {
+    sub(a, b).iter().map(|c| c.abs()).sum()
+}
+
+/// Beacons two scanners must both see for their regions to be considered overlapping.
+const OVERLAP: usize = 12;
+
+/// Finds the rotation and position putting at least [`OVERLAP`] beacons of `scanner` on `known`
+/// beacons.
+fn align(known: &[Vector], scanner: &[Vector]) -> Option<(Rotation, Vector)> {
+    for rotation in Rotation::ALL {
+        let rotated = scanner
+            .iter()
+            .map(|&beacon| rotation.apply(beacon))
+            .collect_vec();
+
+        // Every matching pair of beacons votes for the position of the scanner
+        let mut votes = HashMap::new();
+        for &known in known {
+            for &beacon in &rotated {
+                let position = sub(known, beacon);
+                let count = votes.entry(position).or_insert(0);
+                *count += 1;
+                if *count >= OVERLAP {
+                    return Some((rotation, position));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Scanners and beacons located relative to the first scanner.
+#[derive(Debug)]
+pub struct Map {
+    pub scanners: Vec<Vector>,
+    pub beacons: HashSet<Vector>,
+}
+
+/// Aligns every scanner with one already placed, starting from the first one.
+pub fn map(scanners: &[Scanner]) -> anyhow::Result<Map> {
+    let mut positions = vec![None; scanners.len()];
+    let mut placed = vec![Vec::new(); scanners.len()];
+    positions[0] = Some([0, 0, 0]);
+    placed[0] = scanners.first().context("No scanner")?.clone();
+
+    let mut queue = VecDeque::from([0]);
+    while let Some(reference) = queue.pop_front() {
+        for other in 0..scanners.len() {
+            if positions[other].is_some() {
+                continue;
+            }
+            if let Some((rotation, position)) = align(&placed[reference], &scanners[other]) {
+                placed[other] = scanners[other]
+                    .iter()
+                    .map(|&beacon| add(rotation.apply(beacon), position))
+                    .collect();
+                positions[other] = Some(position);
+                queue.push_back(other);
+            }
+        }
+    }
+
+    let scanners = positions
+        .into_iter()
+        .enumerate()
+        .map(|(i, position)| {
+            position.with_context(|| format!("Scanner {} overlaps none of the others", i))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    Ok(Map {
+        scanners,
+        beacons: placed.into_iter().flatten().collect(),
+    })
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'i> = Map;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> anyhow::Result<Map> {
+        let scanners = common::parse(input, separated_list1(pair(newline, newline), scanner()))?;
+        map(&scanners)
+    }
+
+    fn part1(map: &Map) -> anyhow::Result<usize> {
+        Ok(map.beacons.len())
+    }
+
+    fn part2(map: &Map) -> anyhow::Result<i64> {
+        map.scanners
+            .iter()
+            .tuple_combinations()
+            .map(|(&a, &b)| manhattan(a, b))
+            .max()
+            .context("Need at least two scanners")
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day19::part1(&Day19::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day19::part2(&Day19::parse(input)?)?.into())
+}
+
+common::register_day!(19, Day19);
+
+common::examples!();
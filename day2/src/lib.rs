@@ -1,3 +1,4 @@
+use common::{Answer, Solution};
 use nom::{
     character::complete::{digit1, newline, space1},
     multi::separated_list1,
@@ -6,90 +7,70 @@ use nom::{
 use nom_supreme::parse_from_str;
 
 common::nom_enum!(
-    enum Movement {
+    /// Command steering the submarine.
+    pub enum Movement {
         Forward = "forward",
         Down = "down",
         Up = "up",
     }
 );
 
-fn movements(input: &str) -> anyhow::Result<Vec<(Movement, i64)>> {
-    let movement = separated_pair(Movement::parser(), space1, parse_from_str(digit1));
-    Ok(common::parse(input, separated_list1(newline, movement))?)
-}
+/// Planned course of the submarine.
+pub struct Course(pub Vec<(Movement, i64)>);
 
-pub fn challenge1(input: &str) -> anyhow::Result<i64> {
-    let actions = movements(input)?;
+pub struct Day2;
 
-    let (final_depth, final_range) = actions.into_iter().fold(
-        (0, 0),
-        |(depth, range), (movement, distance)| match movement {
-            Movement::Forward => (depth, range + distance),
-            Movement::Down => (depth + distance, range),
-            Movement::Up => (depth - distance, range),
-        },
-    );
+impl Solution for Day2 {
+    type Parsed<'i> = Course;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    Ok(final_depth * final_range)
-}
+    fn parse(input: &str) -> anyhow::Result<Course> {
+        let movement = separated_pair(Movement::parser(), space1, parse_from_str(digit1));
+        Ok(Course(common::parse(
+            input,
+            separated_list1(newline, movement),
+        )?))
+    }
 
-pub fn challenge2(input: &str) -> anyhow::Result<i64> {
-    let actions = movements(input)?;
+    fn part1(Course(actions): &Course) -> anyhow::Result<i64> {
+        let (final_depth, final_range) = actions.iter().fold(
+            (0, 0),
+            |(depth, range), (movement, distance)| match movement {
+                Movement::Forward => (depth, range + distance),
+                Movement::Down => (depth + distance, range),
+                Movement::Up => (depth - distance, range),
+            },
+        );
 
-    let (final_depth, final_range, _) =
-        actions
-            .into_iter()
-            .fold(
-                (0, 0, 0),
-                |(depth, range, aim), (movement, distance)| match movement {
-                    Movement::Forward => (depth + distance * aim, range + distance, aim),
-                    Movement::Down => (depth, range, aim + distance),
-                    Movement::Up => (depth, range, aim - distance),
-                },
-            );
+        Ok(final_depth * final_range)
+    }
 
-    Ok(final_depth * final_range)
-}
+    fn part2(Course(actions): &Course) -> anyhow::Result<i64> {
+        let (final_depth, final_range, _) =
+            actions
+                .iter()
+                .fold(
+                    (0, 0, 0),
+                    |(depth, range, aim), (movement, distance)| match movement {
+                        Movement::Forward => (depth + distance * aim, range + distance, aim),
+                        Movement::Down => (depth, range, aim + distance),
+                        Movement::Up => (depth, range, aim - distance),
+                    },
+                );
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2"#,
-                150,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+        Ok(final_depth * final_range)
     }
+}
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"forward 5
-down 5
-forward 8
-up 3
-down 8
-forward 2"#,
-                900,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day2::part1(&Day2::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day2::part2(&Day2::parse(input)?)?.into())
 }
+
+common::register_day!(2, Day2);
+
+common::examples!();
@@ -0,0 +1,145 @@
+use std::fmt;
+
+use common::{Answer, Grid, Solution};
+use nom::{
+    branch::alt,
+    character::complete::{char, newline},
+    combinator::verify,
+    multi::many1,
+    sequence::{pair, separated_pair},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, ParserExt};
+
+fn pixel<'i>() -> impl Parser<&'i str, bool, ErrorTree<&'i str>> {
+    alt((char('#').value(true), char('.').value(false)))
+}
+
+/// Number of neighbourhoods of 3 by 3 pixels, each given its output by the algorithm.
+const ALGORITHM_LENGTH: usize = 1 << 9;
+
+fn algorithm<'i>() -> impl Parser<&'i str, Vec<bool>, ErrorTree<&'i str>> {
+    verify(many1(pixel()), |pixels: &Vec<bool>| {
+        pixels.len() == ALGORITHM_LENGTH
+    })
+    .context("algorithm of 512 pixels")
+}
+
+/// Image on an infinite canvas, every pixel outside of `pixels` being of the `background` colour.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Image {
+    pub pixels: Grid<bool>,
+    pub background: bool,
+}
+
+impl Image {
+    /// Pixel at a position which may be outside of the stored pixels.
+    fn get(&self, row: isize, column: isize) -> bool {
+        usize::try_from(row)
+            .ok()
+            .zip(usize::try_from(column).ok())
+            .and_then(|point| self.pixels.get(point))
+            .copied()
+            .unwrap_or(self.background)
+    }
+
+    /// Applies one step of `algorithm`, growing the image by one pixel on every side.
+    ///
+    /// The background is enhanced like any other pixel, so it flips with each step when the
+    /// algorithm lights dark neighbourhoods and darkens lit ones.
+    pub fn enhance(&self, algorithm: &[bool]) -> Image {
+        let mut pixels = Grid::new(self.pixels.height() + 2, self.pixels.width() + 2, false);
+        for (row, column) in pixels.points() {
+            // The new pixel is centered on (row - 1, column - 1) of the current image
+            let index =
+                (0..3)
+                    .flat_map(|dr| (0..3).map(move |dc| (dr, dc)))
+                    .fold(0, |index, (dr, dc)| {
+                        let lit = self.get((row + dr) as isize - 2, (column + dc) as isize - 2);
+                        index << 1 | usize::from(lit)
+                    });
+            pixels[(row, column)] = algorithm[index];
+        }
+
+        let background = algorithm[if self.background {
+            ALGORITHM_LENGTH - 1
+        } else {
+            0
+        }];
+        Image { pixels, background }
+    }
+
+    /// Lit pixels, which are infinitely many when the background is lit.
+    pub fn lit(&self) -> anyhow::Result<usize> {
+        if self.background {
+            anyhow::bail!("The infinite background is lit");
+        }
+        Ok(self.pixels.iter().filter(|(_, &lit)| lit).count())
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pixels.map(|&lit| if lit { '#' } else { '.' }))
+    }
+}
+
+pub struct Trench {
+    pub algorithm: Vec<bool>,
+    pub image: Image,
+}
+
+impl Trench {
+    /// The image once enhanced `steps` times.
+    pub fn image_after(&self, steps: usize) -> Image {
+        (0..steps).fold(self.image.clone(), |image, _| {
+            image.enhance(&self.algorithm)
+        })
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'i> = Trench;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Trench> {
+        let (algorithm, pixels) = common::parse(
+            input,
+            separated_pair(
+                algorithm(),
+                pair(newline, newline),
+                Grid::parser(pixel()).context("image"),
+            ),
+        )?;
+        Ok(Trench {
+            algorithm,
+            image: Image {
+                pixels,
+                background: false,
+            },
+        })
+    }
+
+    fn part1(trench: &Trench) -> anyhow::Result<usize> {
+        trench.image_after(2).lit()
+    }
+
+    fn part2(trench: &Trench) -> anyhow::Result<usize> {
+        trench.image_after(50).lit()
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day20::part1(&Day20::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day20::part2(&Day20::parse(input)?)?.into())
+}
+
+common::register_day!(20, Day20);
+
+common::examples!();
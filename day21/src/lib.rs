@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+
+use common::{trace, Answer, Solution};
+use nom::{
+    character::complete::{digit1, newline},
+    combinator::verify,
+    sequence::{delimited, preceded, separated_pair},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, parse_from_str, tag::complete::tag, ParserExt};
+
+/// Spaces of the circular track, numbered from 1.
+const SPACES: u32 = 10;
+
+fn start<'i>(player: &'static str) -> impl Parser<&'i str, u32, ErrorTree<&'i str>> {
+    preceded(
+        delimited(tag("Player "), tag(player), tag(" starting position: ")),
+        verify(parse_from_str(digit1), |space| (1..=SPACES).contains(space)),
+    )
+    .context("starting position")
+}
+
+/// Where a player lands after moving `steps` from `position`.
+fn advance(position: u32, steps: u32) -> u32 {
+    (position + steps - 1) % SPACES + 1
+}
+
+/// Plays with the deterministic die until a player reaches 1000, returning the score of the loser
+/// times the number of rolls.
+fn deterministic(start: [u32; 2]) -> u32 {
+    let mut positions = start;
+    let mut scores = [0; 2];
+    let mut die = (1..=100).cycle();
+    let mut rolls = 0;
+    let mut player = 0;
+
+    loop {
+        let steps = die.by_ref().take(3).sum();
+        rolls += 3;
+        positions[player] = advance(positions[player], steps);
+        scores[player] += positions[player];
+        if scores[player] >= 1000 {
+            return scores[1 - player] * rolls;
+        }
+        player = 1 - player;
+    }
+}
+
+/// Score a player must reach to win with the Dirac die.
+const DIRAC_GOAL: u32 = 21;
+
+/// Sums of three rolls of the Dirac die, with the number of universes giving each.
+const DIRAC_ROLLS: [(u32, u64); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// State of a Dirac game: positions, scores and the player about to roll.
+type Game = ([u32; 2], [u32; 2], usize);
+
+fn dirac(game: Game, memo: &mut HashMap<Game, [u64; 2]>) -> [u64; 2] {
+    if let Some(&wins) = memo.get(&game) {
+        return wins;
+    }
+
+    let (positions, scores, player) = game;
+    let mut wins = [0; 2];
+    for (steps, universes) in DIRAC_ROLLS {
+        let (mut positions, mut scores) = (positions, scores);
+        positions[player] = advance(positions[player], steps);
+        scores[player] += positions[player];
+        if scores[player] >= DIRAC_GOAL {
+            wins[player] += universes;
+        } else {
+            let next = dirac((positions, scores, 1 - player), memo);
+            wins[0] += universes * next[0];
+            wins[1] += universes * next[1];
+        }
+    }
+
+    memo.insert(game, wins);
+    wins
+}
+
+/// Universes in which each player wins the game with the Dirac die.
+pub fn dirac_wins(start: [u32; 2]) -> [u64; 2] {
+    dirac((start, [0, 0], 0), &mut HashMap::new())
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'i> = [u32; 2];
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<[u32; 2]> {
+        let (player1, player2) =
+            common::parse(input, separated_pair(start("1"), newline, start("2")))?;
+        Ok([player1, player2])
+    }
+
+    fn part1(start: &[u32; 2]) -> anyhow::Result<u32> {
+        Ok(deterministic(*start))
+    }
+
+    fn part2(start: &[u32; 2]) -> anyhow::Result<u64> {
+        let [player1, player2] = dirac_wins(*start);
+        trace::debug!(
+            "player 1 wins in {} universes, player 2 in {}",
+            player1,
+            player2
+        );
+        Ok(player1.max(player2))
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day21::part1(&Day21::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day21::part2(&Day21::parse(input)?)?.into())
+}
+
+common::register_day!(21, Day21);
+
+common::examples!();
+
+#[cfg(test)]
+mod tests {
+    use super::dirac_wins;
+
+    #[test]
+    fn test_dirac_wins() {
+        assert_eq!(dirac_wins([4, 8]), [444356092776315, 341960390180808]);
+    }
+}
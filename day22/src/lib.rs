@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+
+use anyhow::Context;
+use common::{Answer, Solution};
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1, newline},
+    combinator::{opt, recognize, verify},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, parse_from_str, tag::complete::tag, ParserExt};
+
+fn number<'i>() -> impl Parser<&'i str, i64, ErrorTree<&'i str>> {
+    parse_from_str(recognize(tuple((opt(tag("-")), digit1))))
+}
+
+fn range<'i>(axis: &'static str) -> impl Parser<&'i str, (i64, i64), ErrorTree<&'i str>> {
+    preceded(
+        tuple((tag(axis), char('='))),
+        verify(
+            separated_pair(number(), tag(".."), number()),
+            |(min, max)| min <= max,
+        ),
+    )
+}
+
+/// Box of cubes, bounds included.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Cuboid {
+    pub min: [i64; 3],
+    pub max: [i64; 3],
+}
+
+impl Cuboid {
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let min = [0, 1, 2].map(|axis| self.min[axis].max(other.min[axis]));
+        let max = [0, 1, 2].map(|axis| self.max[axis].min(other.max[axis]));
+        (0..3)
+            .all(|axis| min[axis] <= max[axis])
+            .then_some(Cuboid { min, max })
+    }
+
+    pub fn volume(&self) -> i128 {
+        (0..3)
+            .map(|axis| i128::from(self.max[axis]) - i128::from(self.min[axis]) + 1)
+            .product()
+    }
+}
+
+fn cuboid<'i>() -> impl Parser<&'i str, Cuboid, ErrorTree<&'i str>> {
+    tuple((
+        range("x"),
+        preceded(char(','), range("y")),
+        preceded(char(','), range("z")),
+    ))
+    .map(|((x0, x1), (y0, y1), (z0, z1))| Cuboid {
+        min: [x0, y0, z0],
+        max: [x1, y1, z1],
+    })
+}
+
+/// Switches every cube of `cuboid` on or off.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+fn step<'i>() -> impl Parser<&'i str, Step, ErrorTree<&'i str>> {
+    separated_pair(
+        alt((tag("on").value(true), tag("off").value(false))),
+        char(' '),
+        cuboid(),
+    )
+    .map(|(on, cuboid)| Step { on, cuboid })
+    .context("step")
+}
+
+/// Cubes left on by `steps`, counted as a sum of cuboids with signed volumes.
+///
+/// Each step first cancels what it overlaps by adding every existing term's intersection with it,
+/// negated, then adds itself when it switches cubes on. Identical cuboids share their term, which
+/// keeps their number down.
+pub fn cubes_on(steps: impl IntoIterator<Item = Step>) -> anyhow::Result<u64> {
+    let mut terms = HashMap::<Cuboid, i64>::new();
+    for Step { on, cuboid } in steps {
+        let overlaps = terms
+            .iter()
+            .filter_map(|(term, sign)| Some((term.intersection(&cuboid)?, -sign)))
+            .collect::<Vec<_>>();
+        for (overlap, sign) in overlaps {
+            *terms.entry(overlap).or_default() += sign;
+        }
+        if on {
+            *terms.entry(cuboid).or_default() += 1;
+        }
+        terms.retain(|_, sign| *sign != 0);
+    }
+
+    let cubes = terms
+        .iter()
+        .map(|(cuboid, sign)| i128::from(*sign) * cuboid.volume())
+        .sum::<i128>();
+    u64::try_from(cubes).with_context(|| format!("{} cubes can't be on", cubes))
+}
+
+/// Region the initialization procedure is limited to.
+const INITIALIZATION: Cuboid = Cuboid {
+    min: [-50; 3],
+    max: [50; 3],
+};
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'i> = Vec<Step>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<Step>> {
+        Ok(common::parse(input, separated_list1(newline, step()))?)
+    }
+
+    fn part1(steps: &Vec<Step>) -> anyhow::Result<u64> {
+        cubes_on(steps.iter().filter_map(|step| {
+            Some(Step {
+                cuboid: step.cuboid.intersection(&INITIALIZATION)?,
+                ..*step
+            })
+        }))
+    }
+
+    fn part2(steps: &Vec<Step>) -> anyhow::Result<u64> {
+        cubes_on(steps.iter().copied())
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day22::part1(&Day22::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day22::part2(&Day22::parse(input)?)?.into())
+}
+
+common::register_day!(22, Day22);
+
+common::examples!();
@@ -0,0 +1,358 @@
+use std::{fmt, str::FromStr};
+
+use common::{
+    search::{self, Path},
+    trace, Answer, ParseError, Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{char, newline},
+    combinator::verify,
+    multi::{count, separated_list1},
+    sequence::{delimited, terminated, tuple},
+    Parser,
+};
+use nom_supreme::{error::ErrorTree, tag::complete::tag, ParserExt};
+
+common::nom_enum!(
+    /// Kind of amphipod, each with its own room.
+    pub enum Amphipod {
+        Amber = "A",
+        Bronze = "B",
+        Copper = "C",
+        Desert = "D",
+    }
+);
+
+impl Amphipod {
+    fn energy(self) -> u32 {
+        10u32.pow(self as u32)
+    }
+
+    fn room(self) -> usize {
+        self as usize
+    }
+}
+
+const HALLWAY: usize = 11;
+const ROOMS: usize = 4;
+/// Deepest rooms can be, once the diagram is unfolded.
+const MAX_DEPTH: usize = 4;
+
+/// Space of the hallway just outside of `room`, where amphipods never stop.
+fn entrance(room: usize) -> usize {
+    2 + 2 * room
+}
+
+/// Where every amphipod stands, rooms being filled from the bottom without gaps.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Burrow {
+    hallway: [Option<Amphipod>; HALLWAY],
+    /// Spaces of each room from the hallway down, only the first `depth` ones being used.
+    rooms: [[Option<Amphipod>; MAX_DEPTH]; ROOMS],
+    depth: usize,
+}
+
+fn space<'i>() -> impl Parser<&'i str, Option<Amphipod>, ErrorTree<&'i str>> {
+    alt((char('.').value(None), Amphipod::parser().map(Some)))
+}
+
+fn side_room_row<'i>() -> impl Parser<&'i str, Vec<Option<Amphipod>>, ErrorTree<&'i str>> {
+    delimited(
+        alt((tag("###"), tag("  #"))),
+        separated_list1(char('#'), space()),
+        alt((tag("###"), tag("#"))),
+    )
+    .context("side room row")
+}
+
+fn burrow<'i>() -> impl Parser<&'i str, Burrow, ErrorTree<&'i str>> {
+    tuple((
+        terminated(tag("#############"), newline),
+        delimited(char('#'), count(space(), HALLWAY), char('#')).context("hallway"),
+        newline,
+        verify(
+            separated_list1(newline, side_room_row()),
+            |rows: &Vec<_>| rows.len() <= MAX_DEPTH && rows.iter().all(|row| row.len() == ROOMS),
+        ),
+        newline,
+        tag("  #########"),
+    ))
+    .map(|(_, hallway, _, rows, _, _)| {
+        let mut burrow = Burrow {
+            hallway: [None; HALLWAY],
+            rooms: [[None; MAX_DEPTH]; ROOMS],
+            depth: rows.len(),
+        };
+        burrow.hallway.copy_from_slice(&hallway);
+        for (depth, row) in rows.into_iter().enumerate() {
+            for (room, amphipod) in row.into_iter().enumerate() {
+                burrow.rooms[room][depth] = amphipod;
+            }
+        }
+        burrow
+    })
+    .verify(|burrow: &Burrow| {
+        // Amphipods can't have gone past an empty space on their way down
+        burrow.rooms.iter().all(|room| {
+            room[..burrow.depth]
+                .iter()
+                .skip_while(|space| space.is_none())
+                .all(Option::is_some)
+        })
+    })
+    .context("burrow without gaps in its rooms")
+}
+
+impl Burrow {
+    /// The burrow with the two rows folded in the diagram inserted under the first one.
+    pub fn unfolded(&self) -> Burrow {
+        use Amphipod::*;
+        let folded = [
+            [Desert, Desert],
+            [Copper, Bronze],
+            [Bronze, Amber],
+            [Amber, Copper],
+        ];
+
+        let mut unfolded = Burrow {
+            depth: self.depth + 2,
+            ..*self
+        };
+        for (room, folded) in folded.into_iter().enumerate() {
+            unfolded.rooms[room][1] = Some(folded[0]);
+            unfolded.rooms[room][2] = Some(folded[1]);
+            unfolded.rooms[room][3..self.depth + 2]
+                .copy_from_slice(&self.rooms[room][1..self.depth]);
+        }
+        unfolded
+    }
+
+    /// Whether `room` only holds amphipods belonging in it.
+    fn clean(&self, room: usize) -> bool {
+        self.rooms[room][..self.depth]
+            .iter()
+            .flatten()
+            .all(|amphipod| amphipod.room() == room)
+    }
+
+    fn organized(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.rooms[room][..self.depth]
+                .iter()
+                .all(|space| space.is_some_and(|amphipod| amphipod.room() == room))
+        })
+    }
+
+    /// Whether the hallway is free from just after `from` up to `to`.
+    fn clear(&self, from: usize, to: usize) -> bool {
+        let spaces = if from < to {
+            &self.hallway[from + 1..=to]
+        } else {
+            &self.hallway[to..from]
+        };
+        spaces.iter().all(Option::is_none)
+    }
+
+    /// Burrows one move away, with the energy it takes.
+    fn moves(&self) -> Vec<(Burrow, u32)> {
+        // Going into its room is never worse than any other move, so it is the only one tried
+        for (position, space) in self.hallway.iter().enumerate() {
+            if let Some(amphipod) = *space {
+                let room = amphipod.room();
+                if !self.clean(room) || !self.clear(position, entrance(room)) {
+                    continue;
+                }
+                if let Some(depth) = (0..self.depth)
+                    .rev()
+                    .find(|&d| self.rooms[room][d].is_none())
+                {
+                    let mut next = *self;
+                    next.hallway[position] = None;
+                    next.rooms[room][depth] = Some(amphipod);
+                    let steps = position.abs_diff(entrance(room)) + depth + 1;
+                    return vec![(next, steps as u32 * amphipod.energy())];
+                }
+            }
+        }
+
+        let mut moves = Vec::new();
+        for room in (0..ROOMS).filter(|&room| !self.clean(room)) {
+            let depth = match (0..self.depth).find(|&d| self.rooms[room][d].is_some()) {
+                Some(depth) => depth,
+                None => continue,
+            };
+            let amphipod = self.rooms[room][depth].expect("Top of the room is occupied");
+            for position in (0..HALLWAY).filter(|&p| !(0..ROOMS).any(|r| entrance(r) == p)) {
+                if self.clear(entrance(room), position) {
+                    let mut next = *self;
+                    next.rooms[room][depth] = None;
+                    next.hallway[position] = Some(amphipod);
+                    let steps = depth + 1 + position.abs_diff(entrance(room));
+                    moves.push((next, steps as u32 * amphipod.energy()));
+                }
+            }
+        }
+        moves
+    }
+
+    /// Lower bound of the energy left to spend, as if amphipods could walk through each other.
+    fn remaining(&self) -> u32 {
+        let hallway = self
+            .hallway
+            .iter()
+            .enumerate()
+            .filter_map(|(position, space)| {
+                let amphipod = (*space)?;
+                let steps = position.abs_diff(entrance(amphipod.room())) + 1;
+                Some(steps as u32 * amphipod.energy())
+            });
+        let rooms = (0..ROOMS).flat_map(|room| {
+            (0..self.depth).filter_map(move |depth| {
+                let amphipod = self.rooms[room][depth]?;
+                let home = amphipod.room();
+                let steps = if home != room {
+                    depth + 1 + entrance(room).abs_diff(entrance(home)) + 1
+                } else if self.rooms[room][depth + 1..self.depth]
+                    .iter()
+                    .any(|below| below.is_some_and(|below| below.room() != room))
+                {
+                    // Has to step aside to let out those below it
+                    depth + 1 + 2 + 1
+                } else {
+                    0
+                };
+                Some(steps as u32 * amphipod.energy())
+            })
+        });
+        hallway.chain(rooms).sum()
+    }
+
+    /// Cheapest way to get every amphipod into its room.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let diagram = ["#############", "#...........#", "###B#A#C#D###", "  #########"];
+    /// let burrow: day23::Burrow = diagram.join("\n").parse().unwrap();
+    /// let organization = burrow.organize().unwrap();
+    ///
+    /// // Each of them steps into the hallway, then goes home
+    /// assert_eq!(organization.energy(), 46);
+    /// assert_eq!(organization.steps.len(), 5);
+    /// assert_eq!(organization.steps[0], (burrow, 0));
+    /// ```
+    pub fn organize(&self) -> anyhow::Result<Organization> {
+        let Path { nodes, .. } =
+            search::astar(*self, Burrow::moves, Burrow::remaining, Burrow::organized)?;
+
+        let mut steps = vec![(*self, 0)];
+        for next in nodes.into_iter().skip(1) {
+            let (last, energy) = steps[steps.len() - 1];
+            let (_, spent) = last
+                .moves()
+                .into_iter()
+                .find(|(burrow, _)| *burrow == next)
+                .expect("Paths only go through moves");
+            steps.push((next, energy + spent));
+        }
+        Ok(Organization { steps })
+    }
+}
+
+impl FromStr for Burrow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        common::parse(s, burrow())
+    }
+}
+
+/// Moves organizing the amphipods, displayed as every burrow on the way.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Organization {
+    /// Every burrow from the start to the organized one, with the energy spent so far.
+    pub steps: Vec<(Burrow, u32)>,
+}
+
+impl Organization {
+    /// Energy spent by all the moves.
+    pub fn energy(&self) -> u32 {
+        self.steps.last().map_or(0, |&(_, energy)| energy)
+    }
+}
+
+impl fmt::Display for Organization {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (burrow, energy)) in self.steps.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", burrow)?;
+            write!(f, "energy: {}", energy)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let space = |space: &Option<Amphipod>| space.map_or(".".to_owned(), |a| a.to_string());
+
+        writeln!(f, "#############")?;
+        writeln!(
+            f,
+            "#{}#",
+            self.hallway.iter().map(space).collect::<String>()
+        )?;
+        for depth in 0..self.depth {
+            let row = self.rooms.iter().map(|room| space(&room[depth]));
+            let (start, end) = if depth == 0 {
+                ("###", "###")
+            } else {
+                ("  #", "#")
+            };
+            writeln!(f, "{}{}{}", start, row.collect::<Vec<_>>().join("#"), end)?;
+        }
+        write!(f, "  #########")
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'i> = Burrow;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Burrow> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(burrow: &Burrow) -> anyhow::Result<u32> {
+        let organization = burrow.organize()?;
+        trace::debug!("\n{}", organization);
+        Ok(organization.energy())
+    }
+
+    fn part2(burrow: &Burrow) -> anyhow::Result<u32> {
+        if burrow.depth + 2 > MAX_DEPTH {
+            anyhow::bail!("The diagram is already unfolded");
+        }
+        let organization = burrow.unfolded().organize()?;
+        trace::debug!("\n{}", organization);
+        Ok(organization.energy())
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day23::part1(&Day23::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day23::part2(&Day23::parse(input)?)?.into())
+}
+
+common::register_day!(23, Day23);
+
+common::examples!();
@@ -1,4 +1,5 @@
 use anyhow::Context;
+use common::{trace, Answer, Solution};
 use itertools::Itertools;
 
 pub fn bit_counts<'i>(input: impl Iterator<Item = &'i str>) -> Option<Vec<i32>> {
@@ -13,143 +14,108 @@ pub fn bit_counts<'i>(input: impl Iterator<Item = &'i str>) -> Option<Vec<i32>>
         })
         .reduce(|acc, line| {
             acc.into_iter()
-                .zip(line.into_iter())
+                .zip(line)
                 .map(|(acc, bit)| acc + bit)
                 .collect_vec()
         })
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<i64> {
-    let bit_counts = bit_counts(input.lines()).context("No lines")?;
+pub struct Day3;
 
-    let gamma = bit_counts.iter().fold(
-        0,
-        |acc, bit| if *bit >= 0 { acc << 1 | 1 } else { acc << 1 },
-    );
+impl Solution for Day3 {
+    type Parsed<'i> = Vec<&'i str>;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    let epsilon = bit_counts
-        .iter()
-        .fold(0, |acc, bit| if *bit < 0 { acc << 1 | 1 } else { acc << 1 });
+    fn parse(input: &str) -> anyhow::Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
 
-    Ok(gamma * epsilon)
-}
+    fn part1(report: &Vec<&str>) -> anyhow::Result<i64> {
+        let bit_counts = bit_counts(report.iter().copied()).context("No lines")?;
 
-pub fn challenge2(input: &str) -> anyhow::Result<i64> {
-    // let co2_criteria: String = bit_counts
-    //     .iter()
-    //     .map(|i| if *i < 0 { '1' } else { '0' })
-    //     .collect();
+        let gamma = bit_counts.iter().fold(
+            0,
+            |acc, bit| if *bit >= 0 { acc << 1 | 1 } else { acc << 1 },
+        );
 
-    let mut oxygen = input.lines().collect_vec();
-    for index in 0..oxygen.first().context("No lines")?.len() {
-        if oxygen.len() <= 1 {
-            break;
-        }
-        let oxygen_criteria = bit_counts(oxygen.iter().copied())
-            .context("No lines")?
+        let epsilon = bit_counts
             .iter()
-            .map(|i| if *i >= 0 { '1' } else { '0' })
-            .skip(index)
-            .next()
-            .context("One line shorted than the rest")?;
-
-        oxygen.retain(|f| {
-            f.chars()
-                .skip(index)
-                .next()
-                .map(|c| c == oxygen_criteria)
-                .unwrap_or_default()
-        })
+            .fold(0, |acc, bit| if *bit < 0 { acc << 1 | 1 } else { acc << 1 });
+
+        Ok(gamma * epsilon)
     }
 
-    let oxygen_rating = oxygen
-        .into_iter()
-        .exactly_one()
-        .ok()
-        .context("There was not exactly one match")?;
+    fn part2(report: &Vec<&str>) -> anyhow::Result<i64> {
+        // let co2_criteria: String = bit_counts
+        //     .iter()
+        //     .map(|i| if *i < 0 { '1' } else { '0' })
+        //     .collect();
 
-    let mut co2 = input.lines().collect_vec();
-    for index in 0..co2.first().context("No lines")?.len() {
-        if co2.len() <= 1 {
-            break;
-        }
-        let oxygen_criteria = bit_counts(co2.iter().copied())
-            .context("No lines")?
-            .iter()
-            .map(|i| if *i < 0 { '1' } else { '0' })
-            .skip(index)
-            .next()
-            .context("One line shorted than the rest")?;
-
-        co2.retain(|f| {
-            f.chars()
-                .skip(index)
-                .next()
-                .map(|c| c == oxygen_criteria)
-                .unwrap_or_default()
-        })
-    }
-    let co2_rating = co2
-        .into_iter()
-        .exactly_one()
-        .ok()
-        .context("There was not exactly one match")?;
-
-    println!("{} {}", oxygen_rating, oxygen_rating);
-    Ok(i64::from_str_radix(oxygen_rating, 2)? * i64::from_str_radix(co2_rating, 2)?)
-}
+        let mut oxygen = report.clone();
+        for index in 0..oxygen.first().context("No lines")?.len() {
+            if oxygen.len() <= 1 {
+                break;
+            }
+            let oxygen_criteria = bit_counts(oxygen.iter().copied())
+                .context("No lines")?
+                .iter()
+                .map(|i| if *i >= 0 { '1' } else { '0' })
+                .nth(index)
+                .context("One line shorted than the rest")?;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010"#,
-                198,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
+            oxygen.retain(|f| {
+                f.chars()
+                    .nth(index)
+                    .map(|c| c == oxygen_criteria)
+                    .unwrap_or_default()
+            })
         }
-        Ok(())
-    }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"00100
-11110
-10110
-10111
-10101
-01111
-00111
-11100
-10000
-11001
-00010
-01010"#,
-                230,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
+        let oxygen_rating = oxygen
+            .into_iter()
+            .exactly_one()
+            .ok()
+            .context("There was not exactly one match")?;
+
+        let mut co2 = report.clone();
+        for index in 0..co2.first().context("No lines")?.len() {
+            if co2.len() <= 1 {
+                break;
+            }
+            let oxygen_criteria = bit_counts(co2.iter().copied())
+                .context("No lines")?
+                .iter()
+                .map(|i| if *i < 0 { '1' } else { '0' })
+                .nth(index)
+                .context("One line shorted than the rest")?;
+
+            co2.retain(|f| {
+                f.chars()
+                    .nth(index)
+                    .map(|c| c == oxygen_criteria)
+                    .unwrap_or_default()
+            })
         }
-        Ok(())
+        let co2_rating = co2
+            .into_iter()
+            .exactly_one()
+            .ok()
+            .context("There was not exactly one match")?;
+
+        trace::debug!("oxygen rating {}, CO2 rating {}", oxygen_rating, co2_rating);
+        Ok(i64::from_str_radix(oxygen_rating, 2)? * i64::from_str_radix(co2_rating, 2)?)
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day3::part1(&Day3::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day3::part2(&Day3::parse(input)?)?.into())
+}
+
+common::register_day!(3, Day3);
+
+common::examples!();
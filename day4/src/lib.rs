@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::Context;
+use common::{trace, Answer, Solution};
 use itertools::Itertools;
 use nom::{
     character::complete::{char, digit1, newline, space0, space1},
@@ -20,9 +21,9 @@ fn board<'i>() -> impl Parser<&'i str, Vec<Vec<u64>>, ErrorTree<&'i str>> {
     )
 }
 
-fn moves_to_win(board: &Vec<Vec<u64>>, choices: &HashMap<u64, usize>) -> Option<usize> {
+fn moves_to_win(board: &[Vec<u64>], choices: &HashMap<u64, usize>) -> Option<usize> {
     let width = board.len();
-    let height = board.get(0)?.len();
+    let height = board.first()?.len();
 
     if board.iter().any(|row| row.len() != height) {
         return None;
@@ -55,22 +56,19 @@ fn moves_to_win(board: &Vec<Vec<u64>>, choices: &HashMap<u64, usize>) -> Option<
         })
 }
 
-fn game<C>(input: &str, select: C) -> anyhow::Result<u64>
+/// Drawn numbers and the boards playing along.
+pub struct Bingo {
+    pub numbers: Vec<u64>,
+    pub boards: Vec<Vec<Vec<u64>>>,
+}
+
+fn game<C>(Bingo { numbers, boards }: &Bingo, select: C) -> anyhow::Result<u64>
 where
     C: for<'i> FnOnce(
         Box<dyn Iterator<Item = &'i Vec<Vec<u64>>> + 'i>,
         &HashMap<u64, usize>,
     ) -> Option<(&'i Vec<Vec<u64>>, usize)>,
 {
-    let (numbers, boards): (Vec<u64>, Vec<Vec<Vec<u64>>>) = common::parse(
-        input,
-        separated_pair(
-            separated_list1(char(','), parse_from_str(digit1)),
-            newline,
-            separated_list1(newline, board()),
-        ),
-    )?;
-
     let choices = numbers
         .iter()
         .copied()
@@ -82,96 +80,60 @@ where
 
     let unmarked: u64 = winning_board
         .iter()
-        .flat_map(|r| r)
+        .flatten()
         .copied()
         .filter(|n| choices.get(n).filter(|v| **v <= moves).is_none())
         .sum();
 
-    println!("{:?} {}", winning_board, unmarked);
+    trace::debug!("winning board {:?}, {} unmarked", winning_board, unmarked);
     Ok(unmarked * numbers[moves])
 }
 
-pub fn challenge1<'i, 'b>(input: &'i str) -> anyhow::Result<u64> {
-    game(input, |boards, choices| {
-        boards
-            .filter_map(|board| Some((board, moves_to_win(board, &choices)?)))
-            .min_by_key(|(_board, count)| *count)
-    })
-}
+pub struct Day4;
 
-pub fn challenge2<'i, 'b>(input: &'i str) -> anyhow::Result<u64> {
-    game(input, |boards, choices| {
-        boards
-            .filter_map(|board| Some((board, moves_to_win(board, &choices)?)))
-            .max_by_key(|(_board, count)| *count)
-    })
-}
+impl Solution for Day4 {
+    type Parsed<'i> = Bingo;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7"#,
-                4512,
+    fn parse(input: &str) -> anyhow::Result<Bingo> {
+        let (numbers, boards) = common::parse(
+            input,
+            separated_pair(
+                separated_list1(char(','), parse_from_str(digit1)),
+                newline,
+                separated_list1(newline, board()),
             ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+        )?;
+
+        Ok(Bingo { numbers, boards })
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
-
-22 13 17 11  0
- 8  2 23  4 24
-21  9 14 16  7
- 6 10  3 18  5
- 1 12 20 15 19
-
- 3 15  0  2 22
- 9 18 13 17  5
-19  8  7 25 23
-20 11 10 24  4
-14 21 16 12  6
-
-14 21 17 24  4
-10 16 15  9 19
-18  8 23 26 20
-22 11 13  6  5
- 2  0 12  3  7"#,
-                1924,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(bingo: &Bingo) -> anyhow::Result<u64> {
+        game(bingo, |boards, choices| {
+            boards
+                .filter_map(|board| Some((board, moves_to_win(board, choices)?)))
+                .min_by_key(|(_board, count)| *count)
+        })
     }
+
+    fn part2(bingo: &Bingo) -> anyhow::Result<u64> {
+        game(bingo, |boards, choices| {
+            boards
+                .filter_map(|board| Some((board, moves_to_win(board, choices)?)))
+                .max_by_key(|(_board, count)| *count)
+        })
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day4::part1(&Day4::parse(input)?)?.into())
 }
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day4::part2(&Day4::parse(input)?)?.into())
+}
+
+common::register_day!(4, Day4);
+
+common::examples!();
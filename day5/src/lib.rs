@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use common::{Answer, Solution};
 use itertools::Either;
 use nom::{
     bytes::complete::tag,
@@ -28,11 +29,10 @@ fn range(a: i64, b: i64) -> impl Iterator<Item = i64> {
     }
 }
 
-fn challenge(input: &str, diagonals: bool) -> anyhow::Result<usize> {
-    let segments = common::parse(input, separated_list1(newline, segment()))?;
-    let hot_zones = segments.into_iter().fold(
+fn challenge(segments: &[Segment], diagonals: bool) -> anyhow::Result<usize> {
+    let hot_zones = segments.iter().fold(
         HashMap::<(i64, i64), usize>::new(),
-        |mut acc, ((x1, y1), (x2, y2))| {
+        |mut acc, &((x1, y1), (x2, y2))| {
             if x1 == x2 {
                 for y in range(y1, y2) {
                     *acc.entry((x1, y)).or_default() += 1;
@@ -57,61 +57,34 @@ fn challenge(input: &str, diagonals: bool) -> anyhow::Result<usize> {
         .count())
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    challenge(input, false)
-}
+pub struct Day5;
 
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    challenge(input, true)
-}
+impl Solution for Day5 {
+    type Parsed<'i> = Vec<Segment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2"#,
-                5,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn parse(input: &str) -> anyhow::Result<Vec<Segment>> {
+        Ok(common::parse(input, separated_list1(newline, segment()))?)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (
-                r#"0,9 -> 5,9
-8,0 -> 0,8
-9,4 -> 3,4
-2,2 -> 2,1
-7,0 -> 7,4
-6,4 -> 2,0
-0,9 -> 2,9
-3,4 -> 1,4
-0,0 -> 8,8
-5,5 -> 8,2"#,
-                12,
-            ),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(segments: &Vec<Segment>) -> anyhow::Result<usize> {
+        challenge(segments, false)
     }
+
+    fn part2(segments: &Vec<Segment>) -> anyhow::Result<usize> {
+        challenge(segments, true)
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day5::part1(&Day5::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day5::part2(&Day5::parse(input)?)?.into())
 }
+
+common::register_day!(5, Day5);
+
+common::examples!();
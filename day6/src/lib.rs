@@ -1,26 +1,16 @@
 use anyhow::Context;
+use common::{Answer, Solution};
 use nom::{
     character::complete::{char, digit1},
     multi::separated_list1,
 };
 use nom_supreme::parse_from_str;
 
-pub fn challenge1(input: &str) -> anyhow::Result<u64> {
-    challenge(input, 80)
-}
-
-pub fn challenge2(input: &str) -> anyhow::Result<u64> {
-    challenge(input, 256)
-}
-
-fn challenge(input: &str, days: usize) -> anyhow::Result<u64> {
-    let initial_state: Vec<usize> =
-        common::parse(input, separated_list1(char(','), parse_from_str(digit1)))?;
-
+fn challenge(initial_state: &[usize], days: usize) -> anyhow::Result<u64> {
     let mut state = initial_state
-        .into_iter()
+        .iter()
         .try_fold([0; 9], |mut acc, value| {
-            *acc.get_mut(value)? += 1;
+            *acc.get_mut(*value)? += 1;
             Some(acc)
         })
         .context("Some number out of bounds")?;
@@ -37,29 +27,37 @@ fn challenge(input: &str, days: usize) -> anyhow::Result<u64> {
     Ok(state.into_iter().sum())
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("3,4,3,1,2", 5934),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'i> = Vec<usize>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+        Ok(common::parse(
+            input,
+            separated_list1(char(','), parse_from_str(digit1)),
+        )?)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("3,4,3,1,2", 26984457539),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(initial_state: &Vec<usize>) -> anyhow::Result<u64> {
+        challenge(initial_state, 80)
     }
+
+    fn part2(initial_state: &Vec<usize>) -> anyhow::Result<u64> {
+        challenge(initial_state, 256)
+    }
+}
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day6::part1(&Day6::parse(input)?)?.into())
 }
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day6::part2(&Day6::parse(input)?)?.into())
+}
+
+common::register_day!(6, Day6);
+
+common::examples!();
@@ -1,21 +1,12 @@
 use anyhow::Context;
+use common::{Answer, Solution};
 use nom::{
     character::complete::{char, digit1},
     multi::separated_list1,
 };
 use nom_supreme::parse_from_str;
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    challenge(input, |distance| distance)
-}
-
-pub fn challenge2(input: &str) -> anyhow::Result<usize> {
-    challenge(input, |distance| (distance + distance * distance) / 2)
-}
-
-fn challenge(input: &str, mut cost: impl FnMut(usize) -> usize) -> anyhow::Result<usize> {
-    let positions: Vec<usize> =
-        common::parse(input, separated_list1(char(','), parse_from_str(digit1)))?;
+fn challenge(positions: &[usize], mut cost: impl FnMut(usize) -> usize) -> anyhow::Result<usize> {
     let min = *positions.iter().min().context("No input")?;
     let max = *positions.iter().max().context("No input")?;
 
@@ -33,29 +24,37 @@ fn challenge(input: &str, mut cost: impl FnMut(usize) -> usize) -> anyhow::Resul
     Ok(minimum_fuel)
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("16,1,2,0,4,2,7,1,2,14", 37),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'i> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<usize>> {
+        Ok(common::parse(
+            input,
+            separated_list1(char(','), parse_from_str(digit1)),
+        )?)
+    }
+
+    fn part1(positions: &Vec<usize>) -> anyhow::Result<usize> {
+        challenge(positions, |distance| distance)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("16,1,2,0,4,2,7,1,2,14", 168),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part2(positions: &Vec<usize>) -> anyhow::Result<usize> {
+        challenge(positions, |distance| (distance + distance * distance) / 2)
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day7::part1(&Day7::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day7::part2(&Day7::parse(input)?)?.into())
+}
+
+common::register_day!(7, Day7);
+
+common::examples!();
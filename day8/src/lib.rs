@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use anyhow::{bail, Context};
+use common::{Answer, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -35,16 +36,6 @@ fn line<'i>() -> impl Parser<&'i str, (Vec<u8>, Vec<u8>), ErrorTree<&'i str>> {
     )
 }
 
-pub fn challenge1(input: &str) -> anyhow::Result<usize> {
-    let lines = common::parse(input, separated_list1(newline, line()))?;
-
-    Ok(lines
-        .into_iter()
-        .flat_map(|(_, output)| output)
-        .filter(|digit| matches!(digit.count_ones(), 2 | 4 | 3 | 7))
-        .count())
-}
-
 fn build_dictionary(mut options: Vec<u8>) -> anyhow::Result<HashMap<u8, u8>> {
     options.sort_by_key(|f| f.count_ones());
 
@@ -84,57 +75,48 @@ fn build_dictionary(mut options: Vec<u8>) -> anyhow::Result<HashMap<u8, u8>> {
     .collect())
 }
 
-pub fn challenge2(input: &str) -> anyhow::Result<u64> {
-    let lines = common::parse(input, separated_list1(newline, line()))?;
+pub struct Day8;
 
-    lines.into_iter().try_fold(0, |sum, (variants, output)| {
-        let map = build_dictionary(variants)?;
+impl Solution for Day8 {
+    type Parsed<'i> = Vec<(Vec<u8>, Vec<u8>)>;
+    type Answer1 = usize;
+    type Answer2 = u64;
 
-        let line_output = output
+    fn parse(input: &str) -> anyhow::Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        Ok(common::parse(input, separated_list1(newline, line()))?)
+    }
+
+    fn part1(lines: &Vec<(Vec<u8>, Vec<u8>)>) -> anyhow::Result<usize> {
+        Ok(lines
             .iter()
-            .try_fold(0, |acc, value| -> anyhow::Result<u64> {
-                Ok(10 * acc + u64::from(*map.get(value).context("Missing possibility")?))
-            })?;
+            .flat_map(|(_, output)| output)
+            .filter(|digit| matches!(digit.count_ones(), 2 | 4 | 3 | 7))
+            .count())
+    }
 
-        Ok(sum + line_output)
-    })
-}
+    fn part2(lines: &Vec<(Vec<u8>, Vec<u8>)>) -> anyhow::Result<u64> {
+        lines.iter().try_fold(0, |sum, (variants, output)| {
+            let map = build_dictionary(variants.clone())?;
 
-#[cfg(test)]
-mod tests {
-    const EXAMPLE: &str = r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#;
-
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            (EXAMPLE, 26),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
-    }
+            let line_output = output
+                .iter()
+                .try_fold(0, |acc, value| -> anyhow::Result<u64> {
+                    Ok(10 * acc + u64::from(*map.get(value).context("Missing possibility")?))
+                })?;
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-            ("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf", 5353),
-            (EXAMPLE, 61229),
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+            Ok(sum + line_output)
+        })
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day8::part1(&Day8::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Day8::part2(&Day8::parse(input)?)?.into())
+}
+
+common::register_day!(8, Day8);
+
+common::examples!();
@@ -1,32 +1,33 @@
-pub fn challenge1(_input: &str) -> anyhow::Result<i64> {
-    Ok(0)
-}
+use common::{Answer, Solution};
 
-pub fn challenge2(_input: &str) -> anyhow::Result<i64> {
-    Ok(0)
-}
+pub struct Template;
+
+impl Solution for Template {
+    type Parsed<'i> = &'i str;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_challenge1() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge1(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn parse(input: &str) -> anyhow::Result<&str> {
+        Ok(input)
     }
 
-    #[test]
-    fn test_challenge2() -> anyhow::Result<()> {
-        let expected = [
-            // Add tests
-        ];
-        for (input, output) in expected {
-            assert_eq!(crate::challenge2(input)?, output, "For input {}", input)
-        }
-        Ok(())
+    fn part1(_input: &&str) -> anyhow::Result<i64> {
+        Ok(0)
+    }
+
+    fn part2(_input: &&str) -> anyhow::Result<i64> {
+        Ok(0)
     }
 }
+
+pub fn challenge1(input: &str) -> anyhow::Result<Answer> {
+    Ok(Template::part1(&Template::parse(input)?)?.into())
+}
+
+pub fn challenge2(input: &str) -> anyhow::Result<Answer> {
+    Ok(Template::part2(&Template::parse(input)?)?.into())
+}
+
+common::register_day!(0, Template);
+
+common::examples!();